@@ -0,0 +1,294 @@
+pub mod cholesky {
+    use crate::matrix::matrix::{is_negligible, zero_tolerance};
+    use crate::{CMatrix, CMatrixTrait, MatrixError, Matrix};
+
+    /// Cholesky decomposition of symmetric positive definite matrix: A = L * L^T.
+    /// L is lower triangular with positive diagonal
+    #[derive(Debug, Clone)]
+    pub struct Cholesky {
+        pub(crate) n: usize,
+        pub(crate) l: Vec<f64>,
+    }
+
+    /// LDL^T decomposition of symmetric matrix: A = L * D * L^T.
+    /// L is unit lower triangular, D is diagonal. Unlike Cholesky, doesn't need square roots and D may have negative elements.
+    /// There is no pivoting, so it is stable only for positive definite and quasi-definite matrices.
+    /// Other indefinite matrices may fail with `Singular` or give inaccurate factors
+    #[derive(Debug, Clone)]
+    pub struct LDLT {
+        pub(crate) n: usize,
+        pub(crate) l: Vec<f64>,
+        pub(crate) d: Vec<f64>,
+    }
+
+    impl Cholesky {
+        /// Factorizes a square row-major buffer of size n x n
+        pub(crate) fn factorize(a: Vec<f64>, n: usize) -> Result<Cholesky, MatrixError> {
+            if !is_symmetric(&a, n) {
+                return Err(MatrixError::NotSymmetric);
+            }
+
+            let mut l = vec![0.0; n * n];
+
+            for j in 0..n {
+                let s = a[j * n + j] - (0..j).map(|k| l[j * n + k] * l[j * n + k]).sum::<f64>();
+                // NaN fails every comparison, so it is checked separately
+                if s.is_nan() || s <= 0.0 {
+                    return Err(MatrixError::NotPositiveDefinite);
+                }
+                l[j * n + j] = s.sqrt();
+
+                for i in j + 1..n {
+                    let s = a[i * n + j] - (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum::<f64>();
+                    l[i * n + j] = s / l[j * n + j];
+                }
+            }
+
+            Ok(Cholesky { n, l })
+        }
+
+        /// Returns size of factorized matrix
+        pub fn size(&self) -> usize {
+            self.n
+        }
+
+        /// Returns lower triangular matrix L
+        pub fn l(&self) -> CMatrix<f64> {
+            CMatrix {
+                rows: self.n,
+                columns: self.n,
+                elems: self.l.clone(),
+            }
+        }
+
+        /// Counts determinant of factorized matrix
+        pub fn det(&self) -> f64 {
+            let det = (0..self.n).fold(1.0, |acc, i| acc * self.l[i * self.n + i]);
+            det * det
+        }
+
+        /// Solves A * X = B, where B is a vector or a matrix with any amount of columns
+        pub fn solve<B: Matrix<f64> + Clone>(&self, rhs: &B) -> B {
+            match self.try_solve(rhs) {
+                Ok(x) => x,
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        /// Try to solve A * X = B, where B is a vector or a matrix with any amount of columns
+        pub fn try_solve<B: Matrix<f64> + Clone>(&self, rhs: &B) -> Result<B, MatrixError> {
+            if rhs.get_rows() != self.n {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (self.n, rhs.get_columns()),
+                    found: (rhs.get_rows(), rhs.get_columns()),
+                });
+            }
+
+            let mut x = rhs.clone();
+            let k = x.get_columns();
+            forward(&self.l, self.n, x.as_mut_slice(), k, false);
+            backward_transposed(&self.l, self.n, x.as_mut_slice(), k, false);
+            Ok(x)
+        }
+
+        /// Counts inversed matrix
+        pub fn inverse(&self) -> CMatrix<f64> {
+            self.solve(&CMatrix::identity(self.n, self.n))
+        }
+    }
+
+    impl LDLT {
+        /// Factorizes a square row-major buffer of size n x n
+        pub(crate) fn factorize(a: Vec<f64>, n: usize) -> Result<LDLT, MatrixError> {
+            if !is_symmetric(&a, n) {
+                return Err(MatrixError::NotSymmetric);
+            }
+
+            let tol = zero_tolerance(&a, n, n);
+            let mut l = vec![0.0; n * n];
+            let mut d = vec![0.0; n];
+
+            for j in 0..n {
+                d[j] = a[j * n + j] - (0..j).map(|k| l[j * n + k] * l[j * n + k] * d[k]).sum::<f64>();
+                // pivots are compared with the same tolerance as in LU::is_singular
+                if d[j].is_nan() || is_negligible(&d[j], tol) {
+                    return Err(MatrixError::Singular);
+                }
+                l[j * n + j] = 1.0;
+
+                for i in j + 1..n {
+                    let s = a[i * n + j] - (0..j).map(|k| l[i * n + k] * l[j * n + k] * d[k]).sum::<f64>();
+                    l[i * n + j] = s / d[j];
+                }
+            }
+
+            Ok(LDLT { n, l, d })
+        }
+
+        /// Returns size of factorized matrix
+        pub fn size(&self) -> usize {
+            self.n
+        }
+
+        /// Returns unit lower triangular matrix L
+        pub fn l(&self) -> CMatrix<f64> {
+            CMatrix {
+                rows: self.n,
+                columns: self.n,
+                elems: self.l.clone(),
+            }
+        }
+
+        /// Returns diagonal of matrix D
+        pub fn d(&self) -> &[f64] {
+            &self.d
+        }
+
+        /// Checks if factorized matrix is positive definite
+        pub fn is_positive_definite(&self) -> bool {
+            self.d.iter().all(|&d| d > 0.0)
+        }
+
+        /// Counts determinant of factorized matrix
+        pub fn det(&self) -> f64 {
+            self.d.iter().product()
+        }
+
+        /// Solves A * X = B, where B is a vector or a matrix with any amount of columns
+        pub fn solve<B: Matrix<f64> + Clone>(&self, rhs: &B) -> B {
+            match self.try_solve(rhs) {
+                Ok(x) => x,
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        /// Try to solve A * X = B, where B is a vector or a matrix with any amount of columns
+        pub fn try_solve<B: Matrix<f64> + Clone>(&self, rhs: &B) -> Result<B, MatrixError> {
+            if rhs.get_rows() != self.n {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (self.n, rhs.get_columns()),
+                    found: (rhs.get_rows(), rhs.get_columns()),
+                });
+            }
+
+            let mut x = rhs.clone();
+            let k = x.get_columns();
+            let out = x.as_mut_slice();
+
+            forward(&self.l, self.n, out, k, true);
+            for (i, &d) in self.d.iter().enumerate() {
+                for e in out[i * k..(i + 1) * k].iter_mut() {
+                    *e /= d;
+                }
+            }
+            backward_transposed(&self.l, self.n, out, k, true);
+            Ok(x)
+        }
+
+        /// Counts inversed matrix
+        pub fn inverse(&self) -> CMatrix<f64> {
+            self.solve(&CMatrix::identity(self.n, self.n))
+        }
+    }
+
+    /// Checks if square row-major buffer is symmetric up to rounding errors
+    pub(crate) fn is_symmetric(a: &[f64], n: usize) -> bool {
+        let max = a.iter().fold(0.0, |acc: f64, e| acc.max(e.abs()));
+        let tol = max * n as f64 * f64::EPSILON;
+
+        (0..n).all(|i| (0..i).all(|j| (a[i * n + j] - a[j * n + i]).abs() <= tol))
+    }
+
+    /// Solves L * X = B in place for lower triangular L and B with k columns
+    fn forward(l: &[f64], n: usize, x: &mut [f64], k: usize, unit: bool) {
+        for i in 0..n {
+            for j in 0..i {
+                let e = l[i * n + j];
+                for c in 0..k {
+                    x[i * k + c] -= e * x[j * k + c];
+                }
+            }
+            if !unit {
+                for c in 0..k {
+                    x[i * k + c] /= l[i * n + i];
+                }
+            }
+        }
+    }
+
+    /// Solves L^T * X = B in place for lower triangular L and B with k columns
+    fn backward_transposed(l: &[f64], n: usize, x: &mut [f64], k: usize, unit: bool) {
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let e = l[j * n + i];
+                for c in 0..k {
+                    x[i * k + c] -= e * x[j * k + c];
+                }
+            }
+            if !unit {
+                for c in 0..k {
+                    x[i * k + c] /= l[i * n + i];
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cmat, CMatrix, CMatrixTrait, CVector, Matrix, Matrix2, Matrix3, MatrixError};
+
+    #[test]
+    fn cholesky_factors_test() {
+        let a: CMatrix<f64> = cmat![4.0, 12.0, -16.0; 12.0, 37.0, -43.0; -16.0, -43.0, 98.0];
+        let ch = a.cholesky();
+        let mut lt = ch.l();
+        lt.transpose();
+
+        assert_eq!(ch.l(), cmat![2.0, 0.0, 0.0; 6.0, 1.0, 0.0; -8.0, 5.0, 3.0]);
+        assert_eq!(ch.l() * lt, a);
+        assert_eq!(ch.det(), 36.0);
+    }
+
+    #[test]
+    fn cholesky_solve_test() {
+        let a = Matrix3::new(4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0);
+        let ch = a.cholesky();
+        let x = ch.solve(&CVector::new(vec![0.0, 6.0, 39.0]));
+
+        assert!((x - CVector::new(vec![1.0, 1.0, 1.0])).norm() < 1e-12);
+        assert!((ch.inverse() * a.to_f64() - CMatrix::identity(3, 3)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn cholesky_errors_test() {
+        assert!(matches!(Matrix2::new(1, 2, 3, 4).try_cholesky(), Err(MatrixError::NotSymmetric)));
+        assert!(matches!(Matrix2::new(1, 2, 2, 1).try_cholesky(), Err(MatrixError::NotPositiveDefinite)));
+        assert!(matches!(CMatrix::<f64>::one(2, 3).try_cholesky(), Err(MatrixError::NotSquare { rows: 2, columns: 3 })));
+        assert!(matches!(Matrix2::new(f64::NAN, 0.0, 0.0, 1.0).try_cholesky(), Err(MatrixError::NotPositiveDefinite)));
+        assert!(matches!(Matrix2::new(1.0, 0.0, 0.0, f64::NAN).try_ldlt(), Err(MatrixError::Singular)));
+    }
+
+    #[test]
+    fn ldlt_test() {
+        let a = Matrix2::new(1, 2, 2, 1);
+        let ldlt = a.ldlt();
+
+        assert_eq!(ldlt.d(), &[1.0, -3.0]);
+        assert_eq!(ldlt.det(), -3.0);
+        assert!(!ldlt.is_positive_definite());
+        assert_eq!(ldlt.solve(&CVector::new(vec![3.0, 3.0])), CVector::new(vec![1.0, 1.0]));
+        assert!((ldlt.inverse() * a.to_f64() - CMatrix::identity(2, 2)).norm() < 1e-12);
+        assert!(matches!(Matrix2::new(0, 1, 1, 0).try_ldlt(), Err(MatrixError::Singular)));
+
+        // v * v^T + w * w^T has rank 2, rounding leaves a tiny last pivot instead of zero
+        let (v, w) = ([0.3, 0.5, 0.7], [0.2, 0.9, 0.4]);
+        let mut b = CMatrix::zero(3, 3);
+        for i in 0..3 {
+            for j in 0..3 {
+                b[(i, j)] = v[i] * v[j] + w[i] * w[j];
+            }
+        }
+        assert!(matches!(b.try_ldlt(), Err(MatrixError::Singular)));
+    }
+}
@@ -3,237 +3,81 @@ pub mod cmatrix {
 
     pub use crate::cmatrix_trait::cmatrix_trait::CMatrixTrait;
     pub use crate::matrix::matrix::Matrix;
+    use crate::matrix::matrix::{flatten_rows, parse_rows, Transposable};
     use crate::matrix2::matrix2::Matrix2;
     use crate::matrix3::matrix3::Matrix3;
-    use crate::{Error, Matrix23};
+    use crate::matrix4::matrix4::Matrix4;
+    use crate::MatrixError;
 
-    use self::num::Num;
-    use std::{fs::OpenOptions, io::Read};
+    use crate::Scalar;
+    use std::io::Read;
 
+    /// Matrix of custom size. Elements are kept in one row-major buffer
     #[derive(Debug, Default, Clone, Eq)]
-    pub struct CMatrix<T: Num + Default + Clone + Copy + PartialOrd + std::str::FromStr + std::fmt::Debug + std::convert::Into<f64>> {
+    pub struct CMatrix<T: Scalar> {
         pub(crate) rows: usize,
         pub(crate) columns: usize,
-        pub(crate) elems: Vec<Vec<T>>,
+        pub(crate) elems: Vec<T>,
     }
 
-    impl<T: Num + Default + Clone + Copy + PartialOrd + std::str::FromStr + std::fmt::Debug + std::convert::Into<f64>> CMatrixTrait<T> for CMatrix<T> {
-        fn zero(rows: usize, columns: usize) -> Self {
-            let mut e = vec![];
-            let mut v = vec![];
-
-            for _i in 0..columns {
-                v.push(T::zero());
-            }
+    impl<T: Scalar> CMatrix<T> {
+        /// Returns (row stride, column stride) of the underlying buffer
+        pub fn strides(&self) -> (usize, usize) {
+            (self.columns, 1)
+        }
 
-            for _i in 0..rows {
-                e.push(v.clone());
+        /// Returns position of element (row, column) in the underlying buffer
+        pub(crate) fn offset(&self, row: usize, column: usize) -> usize {
+            if row >= self.rows || column >= self.columns {
+                panic!(
+                    "Index ({row}, {column}) is out of bounds for matrix {}x{}",
+                    self.rows, self.columns
+                );
             }
+            let (rs, cs) = self.strides();
+            row * rs + column * cs
+        }
+    }
 
-            CMatrix {
-                rows,
-                columns,
-                elems: e,
-            }
+    impl<T: Scalar> CMatrixTrait<T> for CMatrix<T> {
+        fn zero(rows: usize, columns: usize) -> Self {
+            CMatrix::from_element(rows, columns, T::zero())
         }
 
         fn one(rows: usize, columns: usize) -> Self {
-            let mut e = vec![];
-            let mut v = vec![];
-
-            for _i in 0..columns {
-                v.push(T::one());
-            }
-
-            for _i in 0..rows {
-                e.push(v.clone());
-            }
-
-            CMatrix {
-                rows,
-                columns,
-                elems: e,
-            }
+            CMatrix::from_element(rows, columns, T::one())
         }
 
         fn identity(rows: usize, columns: usize) -> Self {
-            let m = CMatrix::zero(rows, columns);
-            let mut m = m.get_elements();
-
-            for i in 0..rows {
-                for _ in 0..columns {
-                    m[i][i] = T::one();
-                }
-            }
-
-            CMatrix {
-                rows,
-                columns,
-                elems: m,
-            }
+            CMatrix::from_diagonal(rows, columns, T::one())
         }
 
-        fn from_file(filename: String, delimiter: char, rows: usize, columns: usize) -> Self
-        where
-            <T as std::str::FromStr>::Err: std::fmt::Debug,
-        {
-            let mut file = OpenOptions::new()
-                .read(true)
-                .open(filename.clone())
-                .expect(&format!("Can't open file with filename {filename}"));
-
+        fn read_from<R: Read>(mut reader: R, delimiter: char) -> Result<Self, MatrixError> {
             let mut s = String::new();
+            reader.read_to_string(&mut s)?;
 
-            file.read_to_string(&mut s)
-                .expect(&format!("Can't read file with filename {filename}"));
-
-            s = s.trim().to_string();
-
-            let mut e: Vec<&str> = s.split(delimiter).collect();
-
-            if e.len() < rows * columns {
-                for _ in e.len()..rows * columns {
-                    e.push("0");
-                }
-            }
-
-            let mut e: Vec<T> = e
-                .iter()
-                .map(|c| {
-                    c.parse()
-                        .expect("Can't parse file. Maybe some errors in delimiters?")
-                })
-                .collect();
-
-            let t = rows * columns;
-
-            for _ in 0..t {
-                if e.len() < t {
-                    e.push(T::zero());
-                }
-            }
-
-            let mut v = vec![];
-            let mut q = vec![];
-
-            let mut j = 0;
-            while j < e.len() {
-                for _ in 0..rows {
-                    q.push(e[j].clone());
-                    j += 1;
-                }
-                v.push(q.clone());
-                q.clear();
-            }
-
-            let mut r = CMatrix::one(rows, columns);
-
-            r.set_elements(v);
-
-            r
-        }
-
-        fn try_from_file(
-            filename: String,
-            delimiter: char,
-            rows: usize,
-            columns: usize,
-        ) -> Result<Self, Error>
-        where
-            <T as std::str::FromStr>::Err: std::fmt::Debug,
-        {
-            let file = OpenOptions::new().read(true).open(filename.clone());
-
-            let mut file = match file {
-                Ok(file) => file,
-                Err(_) => return Err(Error(format!("Can't open file with filename '{filename}'"))),
-            };
-
-            let mut s = String::new();
-
-            let res = file.read_to_string(&mut s);
-
-            match res {
-                Ok(_) => (),
-                Err(_) => return Err(Error(format!("Can't read file with filename '{filename}'"))),
-            };
-
-            s = s.trim().to_string();
-
-            let mut e: Vec<&str> = s.split(delimiter).collect();
-
-            if e.len() < rows * columns {
-                for _ in e.len()..rows * columns {
-                    e.push("0");
-                }
-            }
-
-            let mut e: Vec<T> = e
-                .iter()
-                .map(|c| {
-                    c.parse()
-                        .expect("Can't parse file. Maybe some errors in delimiters?")
-                })
-                .collect();
-
-            let t = rows * columns;
-
-            for _ in 0..t {
-                if e.len() < t {
-                    e.push(T::zero());
-                }
-            }
-
-            let mut v = vec![];
-            let mut q = vec![];
-
-            let mut j = 0;
-            while j < e.len() {
-                for _ in 0..rows {
-                    q.push(e[j].clone());
-                    j += 1;
-                }
-                v.push(q.clone());
-                q.clear();
-            }
-
-            let mut r = CMatrix::one(rows, columns);
-
-            r.set_elements(v);
-
-            Ok(r)
+            let (rows, columns, elems) = parse_rows(&s, delimiter)?;
+            Ok(CMatrix {
+                rows,
+                columns,
+                elems,
+            })
         }
 
         fn from_element(rows: usize, columns: usize, el: T) -> Self {
-            let mut e = vec![];
-            let mut v = vec![];
-
-            for _i in 0..columns {
-                v.push(el.clone());
-            }
-
-            for _i in 0..rows {
-                e.push(v.clone());
-            }
-
             CMatrix {
                 rows,
                 columns,
-                elems: e,
+                elems: vec![el; rows * columns],
             }
         }
 
         fn from_vec_as_columns(columns: usize, v: Vec<T>) -> Self {
-            let mut e = vec![];
-            let mut t = vec![];
             let rows = v.len();
+            let mut e = Vec::with_capacity(rows * columns);
 
-            for i in 0..rows {
-                for _j in 0..columns {
-                    t.push(v[i].clone());
-                }
-                e.push(t.clone());
-                t.clear();
+            for x in v {
+                e.resize(e.len() + columns, x);
             }
 
             CMatrix {
@@ -244,14 +88,13 @@ pub mod cmatrix {
         }
 
         fn from_vec_as_rows(rows: usize, v: Vec<T>) -> Self {
-            let mut e = vec![];
+            let columns = v.len();
+            let mut e = Vec::with_capacity(rows * columns);
 
-            for _i in 0..rows {
-                e.push(v.clone());
+            for _ in 0..rows {
+                e.extend_from_slice(&v);
             }
 
-            let columns = v.len();
-
             CMatrix {
                 rows,
                 columns,
@@ -261,65 +104,61 @@ pub mod cmatrix {
 
         fn from_diagonal(rows: usize, columns: usize, element: T) -> Self {
             let mut c = CMatrix::zero(rows, columns);
-            let mut e = c.get_elements();
 
-            for i in 0..rows {
-                for j in 0..columns {
-                    e[i][j] = element;
-                }
+            for i in 0..rows.min(columns) {
+                c[(i, i)] = element.clone();
             }
-            c.set_elements(e);
             c
         }
 
         fn to_matrix2(self) -> Matrix2<T> {
-            let mut m = Matrix2::zero();
-            m.set_elements(self.get_elements());
-            m
+            Matrix2::from_cmatrix(self)
         }
 
         fn to_matrix3(self) -> Matrix3<T> {
-            let mut m = Matrix3::zero();
-            m.set_elements(self.get_elements());
-            m
+            Matrix3::from_cmatrix(self)
         }
 
-        fn push(&mut self, v: Vec<T>) {
-            let mut e = self.get_elements();
-            e.push(v);
+        fn to_matrix4(self) -> Matrix4<T> {
+            Matrix4::from_cmatrix(self)
+        }
+
+        fn push(&mut self, mut v: Vec<T>) {
+            if self.rows == 0 {
+                self.columns = v.len();
+            } else if v.len() > self.columns {
+                let columns = v.len();
+                let mut e = Vec::with_capacity((self.rows + 1) * columns);
+
+                for row in self.elems.chunks(self.columns) {
+                    e.extend_from_slice(row);
+                    e.resize(e.len() + columns - self.columns, T::zero());
+                }
+
+                self.columns = columns;
+                self.elems = e;
+            }
+
+            v.resize(self.columns, T::zero());
+            self.elems.extend(v);
             self.rows += 1;
-            self.set_elements(e);
         }
 
         fn pop(&mut self) {
-            let mut e = self.get_elements();
-            e.pop();
-            self.rows -= 1;
-            self.set_elements(e);
+            if self.rows > 0 {
+                self.rows -= 1;
+                self.elems.truncate(self.rows * self.columns);
+            }
         }
     }
 
-    impl<T: Num + Default + Clone + Copy + PartialOrd + std::str::FromStr + std::fmt::Debug + std::convert::Into<f64>> Matrix<T> for CMatrix<T> {
-        fn resize(&mut self) {
-            let mut elems = self.get_elements();
-            let rows = elems.len();
-            let mut lens = vec![];
-            for i in 0..rows {
-                lens.push(elems[i].len());
-            }
-            let max_len = lens.iter().max().expect("Error in resize func!");
-            for i in 0..rows {
-                if elems[i].len() < *max_len {
-                    elems[i].push(T::zero());
-                }
-            }
-            self.rows = rows;
-            self.columns = *max_len;
-            self.elems = elems;
-        }
+    impl<T: Scalar> Transposable for CMatrix<T> {}
 
+    impl<T: Scalar> Matrix<T> for CMatrix<T> {
         fn check_size(&self) {
-            
+            if self.elems.len() != self.rows * self.columns {
+                panic!("CMatrix buffer doesn't match its size: {} != {}x{}", self.elems.len(), self.rows, self.columns);
+            }
         }
 
         fn get_columns(&self) -> usize {
@@ -330,23 +169,35 @@ pub mod cmatrix {
             self.rows
         }
 
-        fn get_elements(&self) -> Vec<Vec<T>> {
-            self.elems.clone()
+        fn set_elements(&mut self, v: Vec<Vec<T>>) {
+            let (rows, columns, elems) = flatten_rows(v);
+            self.rows = rows;
+            self.columns = columns;
+            self.elems = elems;
         }
 
-        fn set_elements(&mut self, v: Vec<Vec<T>>) {
-            self.rows = v.len();
-            self.columns = v[0].len();
-            self.elems = v;
+        fn as_slice(&self) -> &[T] {
+            &self.elems
+        }
+
+        fn as_mut_slice(&mut self) -> &mut [T] {
+            &mut self.elems
+        }
 
-            self.resize();
+        fn into_elements(self) -> Vec<T> {
+            self.elems
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{CMatrix, CMatrixTrait, Matrix, Matrix2, Matrix23, Matrix3};
+    extern crate num;
+
+    use self::num::bigint::BigInt;
+    use self::num::rational::Ratio;
+    use self::num::Complex;
+    use crate::{cmat, CMatrix, CMatrixTrait, CVector, Matrix, Matrix2, Matrix23, Matrix3, MatrixError};
 
     #[test]
     fn cmatrix_i32_add_test() {
@@ -458,4 +309,377 @@ mod tests {
 
         assert_eq!(m.det(), 208);
     }
+
+    #[test]
+    fn cmatrix_row_major_storage_test() {
+        let m = cmat![1, 2, 3; 4, 5, 6];
+
+        assert_eq!(m.strides(), (3, 1));
+        assert_eq!(m.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(&m[1], &[4, 5, 6]);
+        assert_eq!(m[(1, 2)], 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cmatrix_index_column_out_of_bounds_test() {
+        let m = cmat![1, 2, 3; 4, 5, 6];
+
+        let _ = m[(0, 3)];
+    }
+
+    #[test]
+    fn cmatrix_push_pop_test() {
+        let mut m = CMatrix::from_element(2, 2, 1);
+
+        m.push(vec![2, 3, 4]);
+        assert_eq!(m.get_columns(), 3);
+        assert_eq!(m.get_elements(), vec![vec![1, 1, 0], vec![1, 1, 0], vec![2, 3, 4]]);
+
+        m.pop();
+        assert_eq!(m.get_rows(), 2);
+        assert_eq!(m.as_slice(), &[1, 1, 0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn cmatrix_inverse_test() {
+        let mut m: CMatrix<f64> = cmat![2.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 2.0];
+        m.inverse();
+
+        let expected = cmat![0.75, 0.5, 0.25; 0.5, 1.0, 0.5; 0.25, 0.5, 0.75];
+        for (a, b) in m.as_slice().iter().zip(expected.as_slice()) {
+            assert!((a - b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn cmatrix_borrowing_accessors_test() {
+        let mut m = cmat![1, 2, 3; 4, 5, 6];
+
+        assert_eq!(m.get(1, 0), Some(&4));
+        assert_eq!(m.get(0, 3), None);
+        assert_eq!(m.get(2, 0), None);
+        assert_eq!(m.row(0), &[1, 2, 3]);
+
+        *m.get_mut(0, 1).unwrap() = 7;
+        assert_eq!(m[(0, 1)], 7);
+        assert!(m.contains(7));
+        assert_eq!(m.find(6), (1, 2));
+        assert_eq!(m.find(9), (-1, -1));
+
+        assert_eq!(m.into_elements(), vec![1, 7, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn cmatrix_non_square_transpose_test() {
+        let mut m = cmat![1, 2, 3; 4, 5, 6];
+        m.transpose();
+
+        assert_eq!(m, cmat![1, 4; 2, 5; 3, 6]);
+    }
+
+    #[test]
+    fn cmatrix_solve_test() {
+        let m: CMatrix<f64> = cmat![2.0, 0.0, 1.0, 6.0; 3.0, 2.0, 8.0, 4.0; 4.0, 4.0, 4.0, 4.0; 8.0, 7.0, 9.0, 5.0];
+        let x = m.solve(&CVector::new(vec![9.0, 17.0, 16.0, 29.0]));
+
+        assert!((x - CVector::new(vec![1.0, 1.0, 1.0, 1.0])).norm() < 1e-12);
+        assert!(m.try_solve(&CVector::new(vec![1.0, 2.0])).is_err());
+        assert!(CMatrix::<f64>::one(4, 4).try_solve(&CVector::new(vec![1.0; 4])).is_err());
+    }
+
+    #[test]
+    fn cmatrix_error_kinds_test() {
+        let m: CMatrix<f64> = cmat![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+        assert!(matches!(m.try_det(), Err(MatrixError::NotSquare { rows: 2, columns: 3 })));
+        assert!(matches!(
+            m.try_multiplicate(m.clone()),
+            Err(MatrixError::DimensionMismatch { expected: (3, 3), found: (2, 3) })
+        ));
+        assert!(matches!(
+            m.try_remove_row(2),
+            Err(MatrixError::IndexOutOfBounds { index: 2, bound: 2 })
+        ));
+        assert!(matches!(cmat![1.0, 2.0; 2.0, 4.0].try_inverse(), Err(MatrixError::Singular)));
+        assert!(matches!(
+            CMatrix::<f64>::try_from_file("no_such_file.txt", ' '),
+            Err(MatrixError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn cmatrix_checked_ops_test() {
+        let a = cmat![1, 2; 3, 4];
+        let b = cmat![1, 2, 3];
+
+        assert_eq!(a.checked_add(&a).unwrap(), cmat![2, 4; 6, 8]);
+        assert_eq!(a.checked_sub(&Matrix2::new(1, 1, 1, 1)).unwrap(), cmat![0, 1; 2, 3]);
+        assert!(matches!(a.checked_add(&b), Err(MatrixError::DimensionMismatch { .. })));
+        assert!(matches!(a.checked_mul(&b), Err(MatrixError::DimensionMismatch { .. })));
+        assert_eq!(b.checked_mul(&cmat![1; 1; 1]).unwrap(), cmat![6]);
+        assert_eq!(a.checked_index(1, 0).ok(), Some(&3));
+        assert!(matches!(a.checked_index(0, 2), Err(MatrixError::IndexOutOfBounds { index: 2, bound: 2 })));
+        assert!(a.try_insert_row(3, vec![5, 6]).is_err());
+        assert!(a.try_insert_column(3, vec![5, 6]).is_err());
+    }
+
+    #[test]
+    fn cmatrix_insert_replace_shape_test() {
+        let a = cmat![1, 2; 3, 4];
+
+        let c = a.try_insert_column(1, vec![7, 8]).unwrap();
+        assert_eq!((c.rows, c.columns), (2, 3));
+        assert_eq!(c, cmat![1, 7, 2; 3, 8, 4]);
+
+        let r = a.try_insert_row(0, vec![5, 6]).unwrap();
+        assert_eq!((r.rows, r.columns), (3, 2));
+        assert_eq!(a.try_replace_row(1, vec![0, 0]).unwrap(), cmat![1, 2; 0, 0]);
+        assert_eq!(a.try_replace_column(0, vec![9, 9]).unwrap(), cmat![9, 2; 9, 4]);
+        assert!(matches!(
+            a.try_replace_row(0, vec![1]),
+            Err(MatrixError::DimensionMismatch { expected: (1, 2), found: (1, 1) })
+        ));
+    }
+
+    #[test]
+    fn cmatrix_get_rows_columns_test() {
+        let a = cmat![1, 2, 3; 4, 5, 6];
+
+        assert_eq!(a.try_get_rows(1, 1).unwrap(), cmat![4, 5, 6]);
+        assert_eq!(a.try_get_columns(1, 2).unwrap(), cmat![2, 3; 5, 6]);
+
+        let r = a.try_get_rows(0, 0).unwrap();
+        assert_eq!((r.rows, r.columns, r.elems.len()), (0, 3, 0));
+        let c = a.try_get_columns(3, 0).unwrap();
+        assert_eq!((c.rows, c.columns, c.elems.len()), (2, 0, 0));
+
+        assert!(matches!(a.try_get_rows(3, 0), Err(MatrixError::IndexOutOfBounds { index: 3, bound: 2 })));
+        assert!(matches!(a.try_get_rows(2, 1), Err(MatrixError::IndexOutOfBounds { index: 2, bound: 2 })));
+        assert!(matches!(a.try_get_columns(1, 3), Err(MatrixError::IndexOutOfBounds { index: 3, bound: 3 })));
+        assert!(matches!(a.try_get_columns(1, usize::MAX), Err(MatrixError::IndexOutOfBounds { bound: 3, .. })));
+    }
+
+    #[test]
+    fn cmatrix_from_file_parse_error_test() {
+        let path = std::env::temp_dir().join("mematrica_parse_error_test.txt");
+        std::fs::write(&path, "1 2 x 4").unwrap();
+
+        let m = CMatrix::<i32>::try_from_file(path.to_string_lossy().into_owned(), ' ');
+        std::fs::remove_file(&path).unwrap();
+
+        match m {
+            Err(MatrixError::Parse { line, column, token }) => {
+                assert_eq!((line, column), (1, 5));
+                assert_eq!(token, "x");
+            }
+            _ => panic!("expected parse error"),
+        }
+    }
+
+    #[test]
+    fn cmatrix_read_write_test() {
+        let m = cmat![1.5, -2.0, 3.0; 4.0, 5.0, 6.25];
+        let mut buf = Vec::new();
+
+        m.write_to(&mut buf, ',').unwrap();
+        assert_eq!(String::from_utf8(buf.clone()).unwrap(), "# 2 3\n1.5,-2,3\n4,5,6.25\n");
+        assert_eq!(CMatrix::read_from(&buf[..], ',').unwrap(), m);
+    }
+
+    #[test]
+    fn cmatrix_read_write_complex_ratio_test() {
+        let c: CMatrix<Complex<f64>> = cmat![Complex::new(1.0, -2.0), Complex::new(0.5, 0.0); Complex::new(-3.0, 4.0), Complex::new(0.0, 1.0)];
+        let mut buf = Vec::new();
+        c.write_to(&mut buf, ' ').unwrap();
+        assert_eq!(String::from_utf8(buf.clone()).unwrap(), "# 2 2\n1-2i 0.5+0i\n-3+4i 0+1i\n");
+        assert_eq!(CMatrix::read_from(&buf[..], ' ').unwrap(), c);
+
+        let r = |a: i64, b: i64| Ratio::new(BigInt::from(a), BigInt::from(b));
+        let q: CMatrix<Ratio<BigInt>> = cmat![r(1, 2), r(-2, 3); r(5, 1), r(0, 1)];
+        let mut buf = Vec::new();
+        q.write_to(&mut buf, ',').unwrap();
+        assert_eq!(String::from_utf8(buf.clone()).unwrap(), "# 2 2\n1/2,-2/3\n5,0\n");
+        assert_eq!(CMatrix::read_from(&buf[..], ',').unwrap(), q);
+    }
+
+    #[test]
+    fn cmatrix_to_file_truncate_test() {
+        let path = std::env::temp_dir().join("mematrica_truncate_test.txt");
+        let _ = std::fs::remove_file(&path);
+
+        cmat![100, 200, 300, 400].to_file(&path, ' ');
+        cmat![1, 2].to_file(&path, ' ');
+        let m: CMatrix<i32> = CMatrix::from_file(&path, ' ');
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(m, cmat![1, 2]);
+    }
+
+    #[test]
+    fn cmatrix_text_format_test() {
+        let m: CMatrix<i32> = CMatrix::read_from("\n1 2  3\n# comment\n4 5 6\n".as_bytes(), ' ').unwrap();
+        assert_eq!(m, cmat![1, 2, 3; 4, 5, 6]);
+
+        match CMatrix::<i32>::read_from("1, 2, 3\n4, 5\n".as_bytes(), ',') {
+            Err(MatrixError::RaggedRow { line, column, expected, found }) => {
+                assert_eq!((line, column, expected, found), (2, 5, 3, 2));
+            }
+            _ => panic!("expected ragged row error"),
+        }
+        match CMatrix::<i32>::read_from("1 2\n3 4 5 6\n".as_bytes(), ' ') {
+            Err(MatrixError::RaggedRow { line, column, .. }) => assert_eq!((line, column), (2, 5)),
+            _ => panic!("expected ragged row error"),
+        }
+        assert!(matches!(
+            CMatrix::<i32>::read_from("# 3 2\n1 2\n3 4\n".as_bytes(), ' '),
+            Err(MatrixError::DimensionMismatch { expected: (3, 2), found: (2, 2) })
+        ));
+    }
+
+    #[test]
+    fn cmatrix_scalar_types_test() {
+        let a: CMatrix<i64> = cmat![2, 1; 7, 4];
+        assert_eq!(a.det(), 1);
+        assert_eq!(a.clone() * a, cmat![11, 6; 42, 23]);
+
+        let z: CMatrix<Complex<f64>> = cmat![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0); Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)];
+        assert!((z.det() - Complex::new(1.0, -1.0)).norm() < 1e-12);
+
+        let r = |n: i64| Ratio::from_integer(BigInt::from(n));
+        let mut q: CMatrix<Ratio<BigInt>> = cmat![r(2), r(1), r(0); r(1), r(3), r(1); r(0), r(1), r(4)];
+        let inv = q.clone();
+        q.inverse();
+        assert_eq!(inv.det(), r(18));
+        assert_eq!(q * inv, CMatrix::identity(3, 3));
+    }
+
+    #[test]
+    fn cmatrix_adjoint_test() {
+        let c = |re: f64, im: f64| Complex::new(re, im);
+        let a: CMatrix<Complex<f64>> = cmat![c(1.0, 2.0), c(3.0, 0.0), c(0.0, -1.0); c(4.0, 1.0), c(5.0, 0.0), c(6.0, 2.0)];
+
+        assert_eq!(a.adjoint(), cmat![c(1.0, -2.0), c(4.0, -1.0); c(3.0, 0.0), c(5.0, 0.0); c(0.0, 1.0), c(6.0, -2.0)]);
+
+        let h: CMatrix<Complex<f64>> = cmat![c(2.0, 0.0), c(1.0, -1.0); c(1.0, 1.0), c(3.0, 0.0)];
+        assert!(h.is_hermitian());
+        assert!(!a.is_hermitian());
+        assert!(!cmat![c(0.0, 1.0)].is_hermitian());
+
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        let u: CMatrix<Complex<f64>> = cmat![c(s, 0.0), c(0.0, s); c(0.0, s), c(s, 0.0)];
+        assert!(u.is_unitary());
+        assert!(!h.is_unitary());
+        assert!(cmat![0.0, 1.0; 1.0, 0.0].is_unitary());
+    }
+
+    #[test]
+    fn cmatrix_complex_inverse_test() {
+        let c = |re: f64, im: f64| Complex::new(re, im);
+        let a: CMatrix<Complex<f64>> = cmat![c(1.0, 1.0), c(2.0, 0.0), c(0.0, 0.0); c(0.0, 1.0), c(1.0, 0.0), c(3.0, -1.0); c(2.0, 0.0), c(0.0, 0.0), c(1.0, 2.0)];
+        let mut inv = a.clone();
+        inv.inverse();
+
+        assert!((a.clone() * inv - CMatrix::identity(3, 3)).norm() < 1e-12);
+        assert!(a.det().norm() > 0.0);
+    }
+
+    #[test]
+    fn cmatrix_bareiss_det_test() {
+        let n = 8;
+        // old elimination overflowed i64 on this matrix
+        let elems: Vec<i64> = (0..n * n).map(|k| ((k * k * 37 + k * 11 + 5) % 19) as i64 - 9).collect();
+        let a = CMatrix { rows: n, columns: n, elems: elems.clone() };
+        let exact = CMatrix {
+            rows: n,
+            columns: n,
+            elems: elems.iter().map(|&e| Ratio::from_integer(BigInt::from(e))).collect(),
+        };
+        let big = CMatrix { rows: n, columns: n, elems: elems.iter().map(|&e| BigInt::from(e)).collect() };
+
+        assert_eq!(a.det(), 762638492);
+        assert_eq!(Ratio::from_integer(BigInt::from(a.det())), exact.det());
+        assert_eq!(Ratio::from_integer(big.det()), exact.det());
+        assert_eq!(cmat![1, 2; 2, 4].det(), 0);
+        assert_eq!(cmat![0, 1; 1, 0].det(), -1);
+    }
+
+    #[test]
+    fn cmatrix_rational_test() {
+        let r = |n: i64, d: i64| Ratio::new(BigInt::from(n), BigInt::from(d));
+        let a: CMatrix<Ratio<BigInt>> = cmat![
+            r(1, 2), r(1, 3), r(1, 4), r(1, 5);
+            r(1, 3), r(1, 4), r(1, 5), r(1, 6);
+            r(1, 4), r(1, 5), r(1, 6), r(1, 7);
+            r(1, 5), r(1, 6), r(1, 7), r(1, 8)
+        ];
+        let b = CVector::new(vec![r(1, 1), r(0, 1), r(0, 1), r(0, 1)]);
+
+        let x = a.solve(&b);
+        assert_eq!(a.clone() * x, b);
+        assert_eq!(a.det(), r(1, 423360000));
+
+        let mut inv = a.clone();
+        inv.inverse();
+        assert_eq!(inv * a.clone(), CMatrix::identity(4, 4));
+
+        let singular: CMatrix<Ratio<BigInt>> = cmat![r(1, 2), r(1, 3), r(1, 1); r(1, 1), r(2, 3), r(5, 2)];
+        assert_eq!(singular.rref().0, cmat![r(1, 1), r(2, 3), r(0, 1); r(0, 1), r(0, 1), r(1, 1)]);
+        assert_eq!(a.rref().0, CMatrix::identity(4, 4));
+    }
+
+    #[test]
+    fn cmatrix_rref_spaces_test() {
+        let r = |n: i64| Ratio::from_integer(BigInt::from(n));
+        let a: CMatrix<Ratio<BigInt>> = cmat![r(1), r(2), r(1), r(0); r(2), r(4), r(0), r(2); r(3), r(6), r(1), r(2)];
+        let (rref, pivots) = a.rref();
+
+        assert_eq!(pivots, vec![0, 2]);
+        assert_eq!(rref, cmat![r(1), r(2), r(0), r(1); r(0), r(0), r(1), r(-1); r(0), r(0), r(0), r(0)]);
+        assert_eq!(a.rank(), 2);
+        assert!(!a.is_invertible());
+
+        let null = a.null_space();
+        assert_eq!(null, cmat![r(-2), r(-1); r(1), r(0); r(0), r(1); r(0), r(1)]);
+        assert_eq!(a.clone() * null, CMatrix::zero(3, 2));
+        assert_eq!(a.column_space(), cmat![r(1), r(1); r(2), r(0); r(3), r(1)]);
+        assert_eq!(a.row_space(), cmat![r(1), r(2), r(0), r(1); r(0), r(0), r(1), r(-1)]);
+    }
+
+    #[test]
+    fn cmatrix_rank_test() {
+        let a = cmat![1, 2, 3; 4, 5, 6; 7, 8, 9];
+        assert_eq!(a.rank(), 2);
+        assert_eq!(a.column_space(), cmat![1, 2; 4, 5; 7, 8]);
+        assert!(cmat![2, 1; 7, 4].is_invertible());
+        assert!(!CMatrix::<i64>::one(2, 3).is_invertible());
+        assert_eq!(CMatrix::<i64>::zero(2, 2).rank(), 0);
+
+        // rows are dependent only up to rounding errors
+        let f: CMatrix<f64> = cmat![0.1, 0.2, 0.3; 0.4, 0.5, 0.6; 0.7, 0.8, 0.9];
+        let (rref, pivots) = f.rref();
+        assert_eq!(f.rank(), 2);
+        assert_eq!(pivots, vec![0, 1]);
+        assert_eq!(rref.row(2), &[0.0, 0.0, 0.0]);
+        assert!((f.clone() * f.null_space()).norm() < 1e-12);
+        assert!(!Matrix3::new(2.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 1e-20).is_invertible());
+    }
+
+    #[test]
+    fn cmatrix_solve_singular_consistency_test() {
+        let rhs3 = CVector::new(vec![1.0; 3]);
+        let a3: CMatrix<f64> = cmat![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0];
+        let a4: CMatrix<f64> = cmat![1.0, 2.0, 3.0, 4.0; 5.0, 6.0, 7.0, 8.0; 9.0, 10.0, 11.0, 12.0; 13.0, 14.0, 15.0, 16.0];
+        let b3: CMatrix<f64> = cmat![0.1, 0.2, 0.3; 0.4, 0.5, 0.6; 0.7, 0.8, 0.9];
+
+        // closed-form 3x3 path and LU path agree on singular matrices
+        assert!(matches!(a3.try_solve(&rhs3), Err(MatrixError::Singular)));
+        assert!(matches!(a4.try_solve(&CVector::new(vec![1.0; 4])), Err(MatrixError::Singular)));
+        assert!(matches!(b3.try_solve(&rhs3), Err(MatrixError::Singular)));
+        assert!(matches!(b3.clone().try_inverse(), Err(MatrixError::Singular)));
+        assert!(!b3.is_invertible());
+
+        let small: CMatrix<f64> = cmat![1e-10, 2e-10; 3e-10, 1e-10];
+        assert!((small.solve(&CVector::new(vec![3e-10, 4e-10])) - CVector::new(vec![1.0, 1.0])).norm() < 1e-9);
+    }
 }
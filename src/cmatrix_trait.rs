@@ -1,22 +1,34 @@
 pub mod cmatrix_trait {
-    extern crate num;
     
-    use crate::{matrix::matrix::Error, Matrix2, Matrix3};
-    use self::num::Num;
+    use crate::{matrix::matrix::MatrixError, Matrix2, Matrix3, Matrix4};
+    use crate::Scalar;
+    use std::fs::File;
+    use std::io::Read;
+    use std::path::Path;
 
-    pub trait CMatrixTrait<T: Num + Default + Clone + std::str::FromStr + std::cmp::PartialOrd + std::fmt::Debug + std::convert::Into<f64> + std::marker::Copy> {
+    pub trait CMatrixTrait<T: Scalar> {
         /// Creates a matrix of custom size with zero as its elements
         fn zero(rows: usize, columns: usize) -> Self;
         /// Creates a matrix of custom size with one as its elements
         fn one(rows: usize, columns: usize) -> Self;
         /// Creates an identity matrix of custom size
         fn identity(rows: usize, columns: usize) -> Self;
-        /// Reads matrix elements from file
-        fn from_file(filename: String, delimiter: char, rows: usize, columns: usize) -> Self
-        where <T as std::str::FromStr>::Err: std::fmt::Debug;
-        /// Try to read matrix elements from file
-        fn try_from_file(filename: String, delimiter: char, rows: usize, columns: usize) -> Result<Self, Error>
-        where <T as std::str::FromStr>::Err: std::fmt::Debug, Self : Sized;
+        /// Reads matrix from file. Size is taken from `# rows columns` header or from amount of lines and elements in them
+        fn from_file<P: AsRef<Path>>(filename: P, delimiter: char) -> Self
+        where <T as std::str::FromStr>::Err: std::fmt::Debug, Self : Sized {
+            match Self::try_from_file(filename, delimiter) {
+                Ok(m) => m,
+                Err(e) => panic!("{}", e),
+            }
+        }
+        /// Try to read matrix from file. Size is taken from `# rows columns` header or from amount of lines and elements in them
+        fn try_from_file<P: AsRef<Path>>(filename: P, delimiter: char) -> Result<Self, MatrixError>
+        where <T as std::str::FromStr>::Err: std::fmt::Debug, Self : Sized {
+            Self::read_from(File::open(filename)?, delimiter)
+        }
+        /// Try to read matrix from any reader, e.g. socket or in-memory buffer
+        fn read_from<R: Read>(reader: R, delimiter: char) -> Result<Self, MatrixError>
+        where Self : Sized;
         /// Creates a matrix of custom size from element
         fn from_element(rows: usize, columns: usize, e: T) -> Self;
         /// Creates a matrix from this vector, using that elements as columns
@@ -25,10 +37,12 @@ pub mod cmatrix_trait {
         fn from_vec_as_rows(rows: usize, v: Vec<T>) -> Self;
         /// Creates a matrix from this element on its diagonal. All off-diagonal elements are set to 0
         fn from_diagonal(rows: usize, columns: usize, element: T) -> Self;
-        /// Converts matrix to Matrix2
+        /// Converts matrix to Matrix2. Panics if sizes are different, use `Matrix2::try_from_cmatrix` to get an error instead
         fn to_matrix2(self) -> Matrix2<T>;
-        /// Converts matrix to Matrix3
+        /// Converts matrix to Matrix3. Panics if sizes are different, use `Matrix3::try_from_cmatrix` to get an error instead
         fn to_matrix3(self) -> Matrix3<T>;
+        /// Converts matrix to Matrix4. Panics if sizes are different, use `Matrix4::try_from_cmatrix` to get an error instead
+        fn to_matrix4(self) -> Matrix4<T>;
         /// Add a row to matrix
         fn push(&mut self, v: Vec<T>);
         /// Delete last row to matrix
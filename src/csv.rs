@@ -0,0 +1,410 @@
+pub mod csv {
+    use crate::Scalar;
+    use crate::{CMatrix, Matrix, MatrixError};
+    use std::io::{Read, Write};
+
+    /// What to do with empty cells when reading CSV
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum EmptyCells {
+        /// Return parse error
+        Error,
+        /// Read as NaN. Works only for element types which can parse "NaN", e.g. f32 or f64
+        NaN,
+    }
+
+    /// Options for reading CSV
+    #[derive(Debug, Clone)]
+    pub struct CsvOptions {
+        /// Separator of cells
+        pub delimiter: char,
+        /// Amount of lines skipped before header or data, e.g. comments or title of a table
+        pub skip_lines: usize,
+        /// First line after skipped ones holds column names and isn't parsed as data
+        pub has_header: bool,
+        /// What to do with empty cells
+        pub empty_cells: EmptyCells,
+        /// Names of columns to read, in wanted order. Empty means all columns. Needs `has_header`
+        pub columns: Vec<String>,
+    }
+
+    impl Default for CsvOptions {
+        fn default() -> Self {
+            CsvOptions {
+                delimiter: ',',
+                skip_lines: 0,
+                has_header: false,
+                empty_cells: EmptyCells::Error,
+                columns: Vec::new(),
+            }
+        }
+    }
+
+    impl<T: Scalar> CMatrix<T> {
+        /// Reads matrix from CSV. Every line is a row, blank lines are skipped. Quoted fields may hold line breaks
+        pub fn from_csv<R: Read>(reader: R, options: &CsvOptions) -> Result<CMatrix<T>, MatrixError> {
+            Ok(CMatrix::from_csv_with_header(reader, options)?.0)
+        }
+
+        /// Reads matrix from CSV and returns it with names of its columns. Names are empty if there is no header.
+        /// Selecting columns without header returns `ColumnNotFound` for the first of them
+        pub fn from_csv_with_header<R: Read>(
+            mut reader: R,
+            options: &CsvOptions,
+        ) -> Result<(CMatrix<T>, Vec<String>), MatrixError> {
+            let mut s = String::new();
+            reader.read_to_string(&mut s)?;
+
+            if !options.has_header && !options.columns.is_empty() {
+                return Err(MatrixError::ColumnNotFound(options.columns[0].clone()));
+            }
+
+            let mut body = s.as_str();
+            for _ in 0..options.skip_lines {
+                body = body.find('\n').map_or("", |i| &body[i + 1..]);
+            }
+
+            let mut lines = records(body)
+                .into_iter()
+                .map(|(i, line)| (i + options.skip_lines, line))
+                .filter(|(_, line)| !line.trim().is_empty());
+
+            let header: Vec<String> = match lines.next() {
+                Some((_, line)) if options.has_header => {
+                    split_fields(line, options.delimiter).into_iter().map(|(_, f)| f).collect()
+                }
+                Some(first) => {
+                    let m = read_rows(std::iter::once(first).chain(lines), options, None, None)?;
+                    return Ok((m, Vec::new()));
+                }
+                None => Vec::new(),
+            };
+
+            let selected = if options.columns.is_empty() {
+                None
+            } else {
+                let mut indices = Vec::with_capacity(options.columns.len());
+                for name in options.columns.iter() {
+                    match header.iter().position(|h| h == name) {
+                        Some(i) => indices.push(i),
+                        None => return Err(MatrixError::ColumnNotFound(name.clone())),
+                    }
+                }
+                Some(indices)
+            };
+
+            let names = match selected {
+                Some(ref indices) => indices.iter().map(|&i| header[i].clone()).collect(),
+                None => header.clone(),
+            };
+            let width = if header.is_empty() { None } else { Some(header.len()) };
+
+            let m = read_rows(lines, options, width, selected.as_deref())?;
+            Ok((m, names))
+        }
+
+        /// Writes matrix to CSV. Header line is written only if `header` isn't empty.
+        /// Elements are written with `Display`, which `from_csv` parses back, e.g. `1-2i` for complex numbers
+        pub fn to_csv<W: Write>(&self, mut writer: W, delimiter: char, header: &[&str]) -> Result<(), MatrixError>
+        where
+            T: std::fmt::Display,
+        {
+            if !header.is_empty() {
+                if header.len() != self.columns {
+                    return Err(MatrixError::DimensionMismatch {
+                        expected: (1, self.columns),
+                        found: (1, header.len()),
+                    });
+                }
+
+                let fields: Vec<String> = header.iter().map(|h| quote(h, delimiter)).collect();
+                writeln!(writer, "{}", fields.join(&delimiter.to_string()))?;
+            }
+
+            for i in 0..self.rows {
+                let fields: Vec<String> = self.row(i).iter().map(|e| quote(&e.to_string(), delimiter)).collect();
+                writeln!(writer, "{}", fields.join(&delimiter.to_string()))?;
+            }
+            writer.flush()?;
+            Ok(())
+        }
+    }
+
+    /// Parses data lines into matrix, keeping only `selected` columns if they are given.
+    /// If `width` is None, it is taken from the first line
+    fn read_rows<'a, T, I>(
+        lines: I,
+        options: &CsvOptions,
+        mut width: Option<usize>,
+        selected: Option<&[usize]>,
+    ) -> Result<CMatrix<T>, MatrixError>
+    where
+        T: Scalar,
+        I: Iterator<Item = (usize, &'a str)>,
+    {
+        let mut rows = 0;
+        let mut elems = Vec::new();
+
+        for (i, line) in lines {
+            let fields = split_fields(line, options.delimiter);
+            let expected = *width.get_or_insert(fields.len());
+
+            if fields.len() != expected {
+                let column = match fields.get(expected) {
+                    Some(&(column, _)) => column,
+                    None => line.trim_end().chars().count() + 1,
+                };
+                return Err(MatrixError::RaggedRow {
+                    line: i + 1,
+                    column,
+                    expected,
+                    found: fields.len(),
+                });
+            }
+
+            let all: Vec<usize> = (0..fields.len()).collect();
+            for &j in selected.unwrap_or(&all) {
+                let (column, ref field) = fields[j];
+                let token = if field.is_empty() && options.empty_cells == EmptyCells::NaN {
+                    "NaN"
+                } else {
+                    field
+                };
+
+                match token.parse() {
+                    Ok(x) => elems.push(x),
+                    Err(_) => {
+                        return Err(MatrixError::Parse {
+                            line: i + 1,
+                            column,
+                            token: field.clone(),
+                        })
+                    }
+                }
+            }
+            rows += 1;
+        }
+
+        let columns = selected.map_or(width.unwrap_or(0), |s| s.len());
+        Ok(CMatrix { rows, columns, elems })
+    }
+
+    /// Splits CSV text into records with indices of their first lines. Line breaks inside quoted fields don't end a record
+    fn records(s: &str) -> Vec<(usize, &str)> {
+        let mut records = Vec::new();
+        let (mut start, mut first, mut line) = (0, 0, 0);
+        let mut quoted = false;
+
+        for (i, c) in s.char_indices() {
+            match c {
+                '"' => quoted = !quoted,
+                '\n' => {
+                    line += 1;
+                    if !quoted {
+                        records.push((first, s[start..i].trim_end_matches('\r')));
+                        start = i + 1;
+                        first = line;
+                    }
+                }
+                _ => {}
+            }
+        }
+        if start < s.len() {
+            records.push((first, s[start..].trim_end_matches('\r')));
+        }
+        records
+    }
+
+    /// Splits CSV record into fields with their columns, counted from 1.
+    /// Fields may be quoted with `"`, doubled quote inside quoted field means one quote. Spaces around fields are trimmed
+    fn split_fields(line: &str, delimiter: char) -> Vec<(usize, String)> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut start = 1;
+        let mut quoted = false;
+        let mut chars = line.chars().enumerate().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            if quoted {
+                match c {
+                    '"' if chars.peek().map(|&(_, c)| c) == Some('"') => {
+                        field.push('"');
+                        chars.next();
+                    }
+                    '"' => quoted = false,
+                    _ => field.push(c),
+                }
+            } else if c == delimiter {
+                fields.push((start, field.trim().to_string()));
+                field.clear();
+                start = i + 2;
+            } else if c == '"' && field.trim().is_empty() {
+                field.clear();
+                quoted = true;
+            } else {
+                if field.is_empty() && c.is_whitespace() {
+                    start = i + 2;
+                }
+                field.push(c);
+            }
+        }
+        fields.push((start, field.trim().to_string()));
+        fields
+    }
+
+    /// Quotes CSV field if it has delimiter, quotes or line breaks in it
+    fn quote(field: &str, delimiter: char) -> String {
+        if field.contains([delimiter, '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate num;
+
+    use self::num::rational::Ratio;
+    use self::num::Complex;
+    use crate::{cmat, CMatrix, CMatrixTrait, CsvOptions, EmptyCells, Matrix, MatrixError};
+
+    #[test]
+    fn csv_read_test() {
+        let csv = "x, y ,z\n1,2,3\n\n4,5,6\n";
+        let options = CsvOptions {
+            has_header: true,
+            ..CsvOptions::default()
+        };
+        let (m, header) = CMatrix::<i32>::from_csv_with_header(csv.as_bytes(), &options).unwrap();
+
+        assert_eq!(m, cmat![1, 2, 3; 4, 5, 6]);
+        assert_eq!(header, vec!["x", "y", "z"]);
+        assert_eq!(CMatrix::<i32>::from_csv("1;2\n3;4".as_bytes(), &CsvOptions { delimiter: ';', ..CsvOptions::default() }).unwrap(), cmat![1, 2; 3, 4]);
+    }
+
+    #[test]
+    fn csv_select_columns_test() {
+        let csv = "\"name\",\"mass, kg\",speed\nfoo,10.5,3\nbar,20.0,4\n";
+        let options = CsvOptions {
+            has_header: true,
+            columns: vec![String::from("speed"), String::from("mass, kg")],
+            ..CsvOptions::default()
+        };
+        let (m, header) = CMatrix::<f64>::from_csv_with_header(csv.as_bytes(), &options).unwrap();
+
+        assert_eq!(m, cmat![3.0, 10.5; 4.0, 20.0]);
+        assert_eq!(header, vec!["speed", "mass, kg"]);
+
+        let options = CsvOptions {
+            columns: vec![String::from("time")],
+            ..options
+        };
+        assert!(matches!(
+            CMatrix::<f64>::from_csv(csv.as_bytes(), &options),
+            Err(MatrixError::ColumnNotFound(_))
+        ));
+
+        let no_header = CsvOptions {
+            columns: vec![String::from("b")],
+            ..CsvOptions::default()
+        };
+        assert!(matches!(
+            CMatrix::<i32>::from_csv("1,2\n3,4".as_bytes(), &no_header),
+            Err(MatrixError::ColumnNotFound(ref name)) if name == "b"
+        ));
+    }
+
+    #[test]
+    fn csv_empty_cells_test() {
+        let csv = "1,,3\n4,5,6";
+        let nan = CsvOptions {
+            empty_cells: EmptyCells::NaN,
+            ..CsvOptions::default()
+        };
+        let m = CMatrix::<f64>::from_csv(csv.as_bytes(), &nan).unwrap();
+
+        assert!(m[(0, 1)].is_nan());
+        assert!(matches!(
+            CMatrix::<f64>::from_csv(csv.as_bytes(), &CsvOptions::default()),
+            Err(MatrixError::Parse { line: 1, column: 3, .. })
+        ));
+        assert!(CMatrix::<i32>::from_csv(csv.as_bytes(), &nan).is_err());
+        assert!(matches!(
+            CMatrix::<f64>::from_csv("1,2\n3".as_bytes(), &nan),
+            Err(MatrixError::RaggedRow { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn csv_write_test() {
+        let m = cmat![1.5, 2.0; -3.0, 4.0];
+        let mut buf = Vec::new();
+
+        m.to_csv(&mut buf, ',', &["a", "b,c"]).unwrap();
+        assert_eq!(String::from_utf8(buf.clone()).unwrap(), "a,\"b,c\"\n1.5,2\n-3,4\n");
+
+        let options = CsvOptions {
+            has_header: true,
+            ..CsvOptions::default()
+        };
+        let (read, header) = CMatrix::<f64>::from_csv_with_header(&buf[..], &options).unwrap();
+        assert_eq!(read, m);
+        assert_eq!(header, vec!["a", "b,c"]);
+
+        let z = cmat![Complex::new(1.0, -2.0), Complex::new(0.5, 0.0)];
+        let mut buf = Vec::new();
+        z.to_csv(&mut buf, ',', &[]).unwrap();
+        assert_eq!(CMatrix::from_csv(&buf[..], &CsvOptions::default()).unwrap(), z);
+
+        let r = cmat![Ratio::new(1i64, 2), Ratio::new(-3, 4)];
+        let mut buf = Vec::new();
+        r.to_csv(&mut buf, ',', &[]).unwrap();
+        assert_eq!(String::from_utf8(buf.clone()).unwrap(), "1/2,-3/4\n");
+        assert_eq!(CMatrix::from_csv(&buf[..], &CsvOptions::default()).unwrap(), r);
+    }
+
+    #[test]
+    fn csv_skip_lines_test() {
+        let csv = "# exported table\n# units: m\nx,y\n1,2\n3,x";
+        let options = CsvOptions {
+            skip_lines: 2,
+            has_header: true,
+            ..CsvOptions::default()
+        };
+        let one = CsvOptions {
+            skip_lines: 1,
+            ..options.clone()
+        };
+        let (m, header) = CMatrix::<i32>::from_csv_with_header("# comment\nx,y\n1,2\n3,4".as_bytes(), &one).unwrap();
+
+        assert_eq!(m, cmat![1, 2; 3, 4]);
+        assert_eq!(header, vec!["x", "y"]);
+        assert!(matches!(
+            CMatrix::<i32>::from_csv(csv.as_bytes(), &options),
+            Err(MatrixError::Parse { line: 5, column: 3, .. })
+        ));
+        assert_eq!(CMatrix::<i32>::from_csv("a\nb".as_bytes(), &CsvOptions { skip_lines: 5, ..options }).unwrap().rows, 0);
+    }
+
+    #[test]
+    fn csv_multiline_field_test() {
+        let m = cmat![1, 2; 3, 4];
+        let mut buf = Vec::new();
+
+        m.to_csv(&mut buf, ',', &["first\nline", "carriage\r\nreturn"]).unwrap();
+        assert_eq!(String::from_utf8(buf.clone()).unwrap(), "\"first\nline\",\"carriage\r\nreturn\"\n1,2\n3,4\n");
+
+        let options = CsvOptions {
+            has_header: true,
+            ..CsvOptions::default()
+        };
+        let (read, header) = CMatrix::<i32>::from_csv_with_header(&buf[..], &options).unwrap();
+        assert_eq!(read, m);
+        assert_eq!(header, vec!["first\nline", "carriage\r\nreturn"]);
+        assert!(matches!(
+            CMatrix::<i32>::from_csv("\"a\nb\",c\n1,x".as_bytes(), &options),
+            Err(MatrixError::Parse { line: 3, .. })
+        ));
+    }
+}
@@ -0,0 +1,929 @@
+pub mod eigen {
+    extern crate num;
+
+    pub use self::num::Complex;
+    use crate::{CMatrix, CMatrixTrait, MatrixError};
+
+    const MAX_SWEEPS: usize = 64;
+    const MAX_ITERATIONS: usize = 100;
+    /// QR and QL iterations allowed for every row of matrix. NaN and infinities never converge, so they hit this limit
+    const ITERATIONS_PER_ROW: usize = 30;
+    /// Inverse iteration steps made from every start vector. One or two are usually enough
+    const INVERSE_ITERATIONS: usize = 10;
+
+    /// Eigendecomposition of symmetric matrix: A = V * Λ * V^T.
+    /// Eigenvalues are sorted in ascending order, columns of V are orthonormal eigenvectors
+    #[derive(Debug, Clone)]
+    pub struct SymmetricEigen {
+        pub(crate) values: Vec<f64>,
+        pub(crate) vectors: CMatrix<f64>,
+    }
+
+    impl SymmetricEigen {
+        /// Decomposes symmetric row-major buffer of size n x n.
+        /// Small matrices (up to 3x3) use cyclic Jacobi rotations, bigger ones Householder tridiagonalisation and implicit QL
+        pub(crate) fn decompose(a: Vec<f64>, n: usize) -> Result<SymmetricEigen, MatrixError> {
+            let mut v: Vec<Vec<f64>> = (0..n).map(|i| a[i * n..(i + 1) * n].to_vec()).collect();
+            let mut d = vec![0.0; n];
+
+            if n <= 3 {
+                jacobi(&mut v, &mut d)?;
+            } else {
+                let mut e = vec![0.0; n];
+                tridiagonalize(&mut v, &mut d, &mut e);
+                tridiagonal_ql(&mut v, &mut d, &mut e)?;
+            }
+
+            // convergence tests can't fail on NaN, so it is caught here
+            if d.iter().any(|x| !x.is_finite()) {
+                return Err(MatrixError::NoConvergence);
+            }
+
+            let mut order: Vec<usize> = (0..n).collect();
+            order.sort_by(|&i, &j| d[i].total_cmp(&d[j]));
+
+            let mut vectors = CMatrix::zero(n, n);
+            for (j, &k) in order.iter().enumerate() {
+                for i in 0..n {
+                    vectors[(i, j)] = v[i][k];
+                }
+            }
+
+            Ok(SymmetricEigen {
+                values: order.iter().map(|&k| d[k]).collect(),
+                vectors,
+            })
+        }
+
+        /// Returns eigenvalues in ascending order
+        pub fn eigenvalues(&self) -> &[f64] {
+            &self.values
+        }
+
+        /// Returns matrix which columns are orthonormal eigenvectors, in the same order as eigenvalues
+        pub fn eigenvectors(&self) -> CMatrix<f64> {
+            self.vectors.clone()
+        }
+    }
+
+    /// Diagonalizes matrix `a` with cyclic Jacobi rotations. Eigenvalues go to `d`, `a` is replaced by eigenvectors
+    fn jacobi(a: &mut [Vec<f64>], d: &mut [f64]) -> Result<(), MatrixError> {
+        let n = a.len();
+        let mut v: Vec<Vec<f64>> = (0..n)
+            .map(|j| (0..n).map(|i| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+        let total: f64 = a.iter().flatten().map(|x| x * x).sum();
+
+        for _ in 0..MAX_SWEEPS {
+            let off: f64 = (0..n).map(|p| (p + 1..n).map(|q| a[p][q] * a[p][q]).sum::<f64>()).sum();
+            if !off.is_finite() || !total.is_finite() {
+                return Err(MatrixError::NoConvergence);
+            }
+            if off <= f64::EPSILON * f64::EPSILON * total {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+
+                    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        let (x, y) = (row[p], row[q]);
+                        row[p] = c * x - s * y;
+                        row[q] = s * x + c * y;
+                    }
+                    let (top, bottom) = a.split_at_mut(q);
+                    for (x, y) in top[p].iter_mut().zip(bottom[0].iter_mut()) {
+                        let (xp, yq) = (*x, *y);
+                        *x = c * xp - s * yq;
+                        *y = s * xp + c * yq;
+                    }
+                    a[p][q] = 0.0;
+                    a[q][p] = 0.0;
+                }
+            }
+        }
+
+        for (i, e) in d.iter_mut().enumerate() {
+            *e = a[i][i];
+        }
+        a.clone_from_slice(&v);
+        Ok(())
+    }
+
+    /// Reduces symmetric matrix to tridiagonal form with Householder reflections.
+    /// Diagonal goes to `d`, subdiagonal to `e[1..]`, `v` is replaced by accumulated orthogonal transform
+    fn tridiagonalize(v: &mut [Vec<f64>], d: &mut [f64], e: &mut [f64]) {
+        let n = v.len();
+
+        d.copy_from_slice(&v[n - 1]);
+
+        for i in (1..n).rev() {
+            let scale: f64 = d[..i].iter().map(|x| x.abs()).sum();
+            let mut h = 0.0;
+
+            if scale == 0.0 {
+                e[i] = d[i - 1];
+                for j in 0..i {
+                    d[j] = v[i - 1][j];
+                    v[i][j] = 0.0;
+                    v[j][i] = 0.0;
+                }
+            } else {
+                for x in d[..i].iter_mut() {
+                    *x /= scale;
+                    h += *x * *x;
+                }
+
+                let f = d[i - 1];
+                let g = if f > 0.0 { -h.sqrt() } else { h.sqrt() };
+                e[i] = scale * g;
+                h -= f * g;
+                d[i - 1] = f - g;
+
+                for x in e[..i].iter_mut() {
+                    *x = 0.0;
+                }
+                for j in 0..i {
+                    let f = d[j];
+                    v[j][i] = f;
+                    let mut g = e[j] + v[j][j] * f;
+                    for k in j + 1..i {
+                        g += v[k][j] * d[k];
+                        e[k] += v[k][j] * f;
+                    }
+                    e[j] = g;
+                }
+
+                let mut f = 0.0;
+                for j in 0..i {
+                    e[j] /= h;
+                    f += e[j] * d[j];
+                }
+                let hh = f / (h + h);
+                for j in 0..i {
+                    e[j] -= hh * d[j];
+                }
+                for j in 0..i {
+                    let (f, g) = (d[j], e[j]);
+                    for k in j..i {
+                        v[k][j] -= f * e[k] + g * d[k];
+                    }
+                    d[j] = v[i - 1][j];
+                    v[i][j] = 0.0;
+                }
+            }
+            d[i] = h;
+        }
+
+        for i in 0..n - 1 {
+            v[n - 1][i] = v[i][i];
+            v[i][i] = 1.0;
+            let h = d[i + 1];
+
+            if h != 0.0 {
+                for k in 0..=i {
+                    d[k] = v[k][i + 1] / h;
+                }
+                for j in 0..=i {
+                    let g: f64 = (0..=i).map(|k| v[k][i + 1] * v[k][j]).sum();
+                    for k in 0..=i {
+                        v[k][j] -= g * d[k];
+                    }
+                }
+            }
+            for row in v[..=i].iter_mut() {
+                row[i + 1] = 0.0;
+            }
+        }
+
+        for j in 0..n {
+            d[j] = v[n - 1][j];
+            v[n - 1][j] = 0.0;
+        }
+        v[n - 1][n - 1] = 1.0;
+        e[0] = 0.0;
+    }
+
+    /// Diagonalizes tridiagonal matrix with implicitly shifted QL iterations, accumulating rotations into `v`
+    fn tridiagonal_ql(v: &mut [Vec<f64>], d: &mut [f64], e: &mut [f64]) -> Result<(), MatrixError> {
+        let n = v.len();
+        let mut iterations = 0;
+
+        e.copy_within(1.., 0);
+        e[n - 1] = 0.0;
+
+        let mut f = 0.0;
+        let mut tst1: f64 = 0.0;
+
+        for l in 0..n {
+            tst1 = tst1.max(d[l].abs() + e[l].abs());
+            let mut m = l;
+            while m < n - 1 && e[m].abs() > f64::EPSILON * tst1 {
+                m += 1;
+            }
+
+            if m > l {
+                loop {
+                    iterations += 1;
+                    if iterations > ITERATIONS_PER_ROW * n {
+                        return Err(MatrixError::NoConvergence);
+                    }
+
+                    let g = d[l];
+                    let p = (d[l + 1] - g) / (2.0 * e[l]);
+                    let r = if p < 0.0 { -p.hypot(1.0) } else { p.hypot(1.0) };
+                    d[l] = e[l] / (p + r);
+                    d[l + 1] = e[l] * (p + r);
+                    let dl1 = d[l + 1];
+                    let h = g - d[l];
+                    for x in d[l + 2..].iter_mut() {
+                        *x -= h;
+                    }
+                    f += h;
+
+                    let mut p = d[m];
+                    let (mut c, mut c2, mut c3) = (1.0, 1.0, 1.0);
+                    let el1 = e[l + 1];
+                    let (mut s, mut s2) = (0.0, 0.0);
+
+                    for i in (l..m).rev() {
+                        c3 = c2;
+                        c2 = c;
+                        s2 = s;
+                        let g = c * e[i];
+                        let h = c * p;
+                        let r = p.hypot(e[i]);
+                        e[i + 1] = s * r;
+                        s = e[i] / r;
+                        c = p / r;
+                        p = c * d[i] - s * g;
+                        d[i + 1] = h + s * (c * g + s * d[i]);
+
+                        for row in v.iter_mut() {
+                            let h = row[i + 1];
+                            row[i + 1] = s * row[i] + c * h;
+                            row[i] = c * row[i] - s * h;
+                        }
+                    }
+
+                    p = -s * s2 * c3 * el1 * e[l] / dl1;
+                    e[l] = s * p;
+                    d[l] = c * p;
+
+                    if e[l].abs() <= f64::EPSILON * tst1 {
+                        break;
+                    }
+                }
+            }
+
+            d[l] += f;
+            e[l] = 0.0;
+        }
+
+        Ok(())
+    }
+
+    /// Real Schur decomposition of square matrix: A = Z * T * Z^T.
+    /// Z is orthogonal, T is upper quasi-triangular: 1x1 diagonal blocks hold real eigenvalues, 2x2 blocks hold complex conjugate pairs
+    #[derive(Debug, Clone)]
+    pub struct RealSchur {
+        pub(crate) t: CMatrix<f64>,
+        pub(crate) z: CMatrix<f64>,
+        pub(crate) values: Vec<Complex<f64>>,
+    }
+
+    impl RealSchur {
+        /// Decomposes row-major buffer of size n x n: reduces it to upper Hessenberg form and runs Francis double shift QR iterations
+        pub(crate) fn decompose(a: Vec<f64>, n: usize) -> Result<RealSchur, MatrixError> {
+            let mut h: Vec<Vec<f64>> = (0..n).map(|i| a[i * n..(i + 1) * n].to_vec()).collect();
+            let mut v = hessenberg(&mut h);
+            let (d, e) = francis_qr(&mut h, &mut v)?;
+            if d.iter().chain(e.iter()).any(|x| !x.is_finite()) {
+                return Err(MatrixError::NoConvergence);
+            }
+
+            let mut t = CMatrix::zero(n, n);
+            let mut z = CMatrix::zero(n, n);
+            for i in 0..n {
+                for j in 0..n {
+                    let inside_block = j + 1 == i && e[j] > 0.0;
+                    if j >= i || inside_block {
+                        t[(i, j)] = h[i][j];
+                    }
+                    z[(i, j)] = v[i][j];
+                }
+            }
+
+            Ok(RealSchur {
+                t,
+                z,
+                values: d.iter().zip(e).map(|(&re, im)| Complex::new(re, im)).collect(),
+            })
+        }
+
+        /// Returns upper quasi-triangular matrix T
+        pub fn t(&self) -> CMatrix<f64> {
+            self.t.clone()
+        }
+
+        /// Returns orthogonal matrix Z
+        pub fn z(&self) -> CMatrix<f64> {
+            self.z.clone()
+        }
+
+        /// Returns eigenvalues in order of diagonal blocks of T. Complex conjugate pairs go one after another, positive imaginary part first
+        pub fn eigenvalues(&self) -> &[Complex<f64>] {
+            &self.values
+        }
+    }
+
+    /// Reduces matrix to upper Hessenberg form with Householder reflections. Returns accumulated orthogonal transform
+    #[allow(clippy::needless_range_loop)]
+    fn hessenberg(h: &mut [Vec<f64>]) -> Vec<Vec<f64>> {
+        let n = h.len();
+        let high = n.saturating_sub(1);
+        let mut ort = vec![0.0; n];
+
+        for m in 1..high {
+            let scale: f64 = (m..=high).map(|i| h[i][m - 1].abs()).sum();
+            if scale == 0.0 {
+                continue;
+            }
+
+            let mut sum = 0.0;
+            for i in (m..=high).rev() {
+                ort[i] = h[i][m - 1] / scale;
+                sum += ort[i] * ort[i];
+            }
+            let g = if ort[m] > 0.0 { -sum.sqrt() } else { sum.sqrt() };
+            sum -= ort[m] * g;
+            ort[m] -= g;
+
+            for j in m..n {
+                let f = (m..=high).map(|i| ort[i] * h[i][j]).sum::<f64>() / sum;
+                for i in m..=high {
+                    h[i][j] -= f * ort[i];
+                }
+            }
+            for row in h.iter_mut() {
+                let f = (m..=high).map(|j| ort[j] * row[j]).sum::<f64>() / sum;
+                for j in m..=high {
+                    row[j] -= f * ort[j];
+                }
+            }
+
+            ort[m] *= scale;
+            h[m][m - 1] = scale * g;
+        }
+
+        let mut v: Vec<Vec<f64>> = (0..n)
+            .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+
+        for m in (1..high).rev() {
+            if h[m][m - 1] == 0.0 {
+                continue;
+            }
+
+            for i in m + 1..=high {
+                ort[i] = h[i][m - 1];
+            }
+            for j in m..=high {
+                let g = (m..=high).map(|i| ort[i] * v[i][j]).sum::<f64>() / ort[m] / h[m][m - 1];
+                for i in m..=high {
+                    v[i][j] += g * ort[i];
+                }
+            }
+        }
+
+        for (i, row) in h.iter_mut().enumerate() {
+            for e in row[..i.saturating_sub(1)].iter_mut() {
+                *e = 0.0;
+            }
+        }
+        v
+    }
+
+    /// Reduces Hessenberg matrix to real Schur form with Francis double shift QR iterations, accumulating transforms into `v`.
+    /// Returns real and imaginary parts of eigenvalues
+    #[allow(clippy::needless_range_loop)]
+    fn francis_qr(h: &mut [Vec<f64>], v: &mut [Vec<f64>]) -> Result<(Vec<f64>, Vec<f64>), MatrixError> {
+        let nn = h.len();
+        let mut d = vec![0.0; nn];
+        let mut e = vec![0.0; nn];
+
+        let norm: f64 = (0..nn)
+            .map(|i| h[i][i.saturating_sub(1)..].iter().map(|x| x.abs()).sum::<f64>())
+            .sum();
+        let mut exshift = 0.0;
+        let mut iter = 0;
+        let mut total = 0;
+        let mut top = nn as isize - 1;
+
+        while top >= 0 {
+            let n = top as usize;
+
+            let mut l = n;
+            while l > 0 {
+                let mut s = h[l - 1][l - 1].abs() + h[l][l].abs();
+                if s == 0.0 {
+                    s = norm;
+                }
+                if h[l][l - 1].abs() < f64::EPSILON * s {
+                    break;
+                }
+                l -= 1;
+            }
+
+            if l == n {
+                h[n][n] += exshift;
+                d[n] = h[n][n];
+                e[n] = 0.0;
+                top -= 1;
+                iter = 0;
+            } else if l == n - 1 {
+                let w = h[n][n - 1] * h[n - 1][n];
+                let p = (h[n - 1][n - 1] - h[n][n]) / 2.0;
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                h[n][n] += exshift;
+                h[n - 1][n - 1] += exshift;
+                let x = h[n][n];
+
+                if q >= 0.0 {
+                    let z = if p >= 0.0 { p + z } else { p - z };
+                    d[n - 1] = x + z;
+                    d[n] = if z != 0.0 { x - w / z } else { x + z };
+                    e[n - 1] = 0.0;
+                    e[n] = 0.0;
+
+                    let x = h[n][n - 1];
+                    let s = x.abs() + z.abs();
+                    let (p, q) = (x / s, z / s);
+                    let r = (p * p + q * q).sqrt();
+                    let (p, q) = (p / r, q / r);
+
+                    for j in n - 1..nn {
+                        let z = h[n - 1][j];
+                        h[n - 1][j] = q * z + p * h[n][j];
+                        h[n][j] = q * h[n][j] - p * z;
+                    }
+                    for row in h[..=n].iter_mut().chain(v.iter_mut()) {
+                        let z = row[n - 1];
+                        row[n - 1] = q * z + p * row[n];
+                        row[n] = q * row[n] - p * z;
+                    }
+                    h[n][n - 1] = 0.0;
+                } else {
+                    d[n - 1] = x + p;
+                    d[n] = x + p;
+                    e[n - 1] = z;
+                    e[n] = -z;
+                }
+                top -= 2;
+                iter = 0;
+            } else {
+                let mut x = h[n][n];
+                let mut y = h[n - 1][n - 1];
+                let mut w = h[n][n - 1] * h[n - 1][n];
+
+                if iter == 10 {
+                    exshift += x;
+                    for i in 0..=n {
+                        h[i][i] -= x;
+                    }
+                    let s = h[n][n - 1].abs() + h[n - 1][n - 2].abs();
+                    x = 0.75 * s;
+                    y = x;
+                    w = -0.4375 * s * s;
+                }
+                if iter == 30 {
+                    let s = (y - x) / 2.0;
+                    let s = s * s + w;
+                    if s > 0.0 {
+                        let s = if y < x { -s.sqrt() } else { s.sqrt() };
+                        let s = x - w / ((y - x) / 2.0 + s);
+                        for i in 0..=n {
+                            h[i][i] -= s;
+                        }
+                        exshift += s;
+                        x = 0.964;
+                        y = x;
+                        w = x;
+                    }
+                }
+
+                iter += 1;
+                total += 1;
+                if iter > MAX_ITERATIONS || total > ITERATIONS_PER_ROW * nn.max(1) {
+                    return Err(MatrixError::NoConvergence);
+                }
+
+                let (mut p, mut q, mut r);
+                let mut m = n - 2;
+                loop {
+                    let z = h[m][m];
+                    r = x - z;
+                    let s = y - z;
+                    p = (r * s - w) / h[m + 1][m] + h[m][m + 1];
+                    q = h[m + 1][m + 1] - z - r - s;
+                    r = h[m + 2][m + 1];
+                    let s = p.abs() + q.abs() + r.abs();
+                    p /= s;
+                    q /= s;
+                    r /= s;
+
+                    if m == l {
+                        break;
+                    }
+                    let left = h[m][m - 1].abs() * (q.abs() + r.abs());
+                    let right = f64::EPSILON * (p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs()));
+                    if left < right {
+                        break;
+                    }
+                    m -= 1;
+                }
+
+                for i in m + 2..=n {
+                    h[i][i - 2] = 0.0;
+                    if i > m + 2 {
+                        h[i][i - 3] = 0.0;
+                    }
+                }
+
+                for k in m..n {
+                    let notlast = k != n - 1;
+                    let mut x = 0.0;
+
+                    if k != m {
+                        p = h[k][k - 1];
+                        q = h[k + 1][k - 1];
+                        r = if notlast { h[k + 2][k - 1] } else { 0.0 };
+                        x = p.abs() + q.abs() + r.abs();
+                        if x == 0.0 {
+                            continue;
+                        }
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+
+                    let s = if p < 0.0 { -(p * p + q * q + r * r).sqrt() } else { (p * p + q * q + r * r).sqrt() };
+                    if s == 0.0 {
+                        continue;
+                    }
+
+                    if k != m {
+                        h[k][k - 1] = -s * x;
+                    } else if l != m {
+                        h[k][k - 1] = -h[k][k - 1];
+                    }
+
+                    p += s;
+                    let (x, y, z) = (p / s, q / s, r / s);
+                    q /= p;
+                    r /= p;
+
+                    for j in k..nn {
+                        let mut p = h[k][j] + q * h[k + 1][j];
+                        if notlast {
+                            p += r * h[k + 2][j];
+                            h[k + 2][j] -= p * z;
+                        }
+                        h[k][j] -= p * x;
+                        h[k + 1][j] -= p * y;
+                    }
+                    let last = n.min(k + 3);
+                    for row in h[..=last].iter_mut().chain(v.iter_mut()) {
+                        let mut p = x * row[k] + y * row[k + 1];
+                        if notlast {
+                            p += z * row[k + 2];
+                            row[k + 2] -= p * r;
+                        }
+                        row[k] -= p;
+                        row[k + 1] -= p * q;
+                    }
+                }
+            }
+        }
+
+        Ok((d, e))
+    }
+
+    /// Counts unit eigenvector for every eigenvalue with inverse iteration on complex matrix A - λI.
+    /// Iterates until residual |A * x - λ * x| is small, trying unit vectors as start if the first one fails.
+    /// Vectors of equal eigenvalues are orthogonalized against each other, so repeated eigenvalues get independent vectors.
+    /// Returns `NoConvergence` if there is no such vector, i.e. matrix is defective
+    pub(crate) fn inverse_iteration(
+        a: &[f64],
+        n: usize,
+        values: &[Complex<f64>],
+    ) -> Result<Vec<Vec<Complex<f64>>>, MatrixError> {
+        let norm = a.iter().map(|x| x.abs()).fold(0.0, f64::max).max(1.0);
+        let tiny = norm * f64::EPSILON;
+        // eigenvalues of defective matrices are known only up to sqrt(eps), so they are compared with it
+        let close = norm * f64::EPSILON.sqrt();
+        let tol = close * n as f64;
+        let mut vectors: Vec<Vec<Complex<f64>>> = Vec::with_capacity(values.len());
+
+        for (k, &lambda) in values.iter().enumerate() {
+            let mut m: Vec<Complex<f64>> = a.iter().map(|&x| Complex::new(x, 0.0)).collect();
+            for i in 0..n {
+                m[i * n + i] -= lambda;
+            }
+            let perm = complex_lu(&mut m, n, tiny);
+
+            let equal: Vec<&Vec<Complex<f64>>> =
+                (0..k).filter(|&j| (values[j] - lambda).norm() <= close).map(|j| &vectors[j]).collect();
+            let residual = |x: &[Complex<f64>]| {
+                (0..n)
+                    .map(|i| {
+                        let ax: Complex<f64> = (0..n).map(|j| x[j] * a[i * n + j]).sum();
+                        (ax - lambda * x[i]).norm_sqr()
+                    })
+                    .sum::<f64>()
+                    .sqrt()
+            };
+
+            // start vector with distinct elements, then unit vectors
+            let mut starts: Vec<Vec<Complex<f64>>> = vec![(0..n).map(|i| Complex::new(1.0 + i as f64 / n as f64, 0.0)).collect()];
+            for i in 0..n {
+                let mut e = vec![Complex::new(0.0, 0.0); n];
+                e[i] = Complex::new(1.0, 0.0);
+                starts.push(e);
+            }
+
+            let mut found = None;
+            for mut x in starts {
+                if !orthonormalize(&mut x, &equal) {
+                    continue;
+                }
+
+                for _ in 0..INVERSE_ITERATIONS {
+                    let mut y: Vec<Complex<f64>> = perm.iter().map(|&p| x[p]).collect();
+                    for i in 0..n {
+                        for j in 0..i {
+                            let l = m[i * n + j] * y[j];
+                            y[i] -= l;
+                        }
+                    }
+                    for i in (0..n).rev() {
+                        for j in i + 1..n {
+                            let u = m[i * n + j] * y[j];
+                            y[i] -= u;
+                        }
+                        y[i] /= m[i * n + i];
+                    }
+
+                    if !orthonormalize(&mut y, &equal) {
+                        break;
+                    }
+                    x = y;
+                    if residual(&x) <= tol {
+                        found = Some(x);
+                        break;
+                    }
+                }
+                if found.is_some() {
+                    break;
+                }
+            }
+
+            let x = found.ok_or(MatrixError::NoConvergence)?;
+            let max = x.iter().fold(Complex::new(0.0, 0.0), |acc, &c| if c.norm() > acc.norm() { c } else { acc });
+            let phase = max / max.norm();
+            vectors.push(x.iter().map(|&c| c / phase).collect());
+        }
+        Ok(vectors)
+    }
+
+    /// Removes components along unit vectors `basis` from `x` and normalizes it.
+    /// Returns false if `x` isn't finite or almost lies in span of `basis`
+    fn orthonormalize(x: &mut [Complex<f64>], basis: &[&Vec<Complex<f64>>]) -> bool {
+        let length = |x: &[Complex<f64>]| x.iter().map(|c| c.norm_sqr()).sum::<f64>().sqrt();
+        let before = length(x);
+
+        // the second pass removes what rounding errors of the first one left
+        for _ in 0..2 {
+            for v in basis {
+                let dot: Complex<f64> = v.iter().zip(x.iter()).map(|(a, b)| a.conj() * b).sum();
+                for (e, a) in x.iter_mut().zip(v.iter()) {
+                    *e -= dot * a;
+                }
+            }
+        }
+
+        let len = length(x);
+        if !len.is_finite() || len <= before * f64::EPSILON.sqrt() {
+            return false;
+        }
+        for e in x.iter_mut() {
+            *e /= len;
+        }
+        true
+    }
+
+    /// LU decomposition with partial pivoting of complex matrix in place. Zero pivots are replaced by `tiny`
+    fn complex_lu(m: &mut [Complex<f64>], n: usize, tiny: f64) -> Vec<usize> {
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            let max_row = (k..n).fold(k, |acc, i| if m[i * n + k].norm() > m[acc * n + k].norm() { i } else { acc });
+            if max_row != k {
+                for j in 0..n {
+                    m.swap(k * n + j, max_row * n + j);
+                }
+                perm.swap(k, max_row);
+            }
+
+            if m[k * n + k].norm() < tiny {
+                m[k * n + k] = Complex::new(tiny, 0.0);
+            }
+            let pivot = m[k * n + k];
+
+            for i in k + 1..n {
+                let factor = m[i * n + k] / pivot;
+                m[i * n + k] = factor;
+                for j in k + 1..n {
+                    let u = m[k * n + j];
+                    m[i * n + j] -= factor * u;
+                }
+            }
+        }
+        perm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cmat, CMatrix, CMatrixTrait, Complex, Matrix, Matrix2, Matrix3, MatrixError};
+
+    fn check(a: CMatrix<f64>, values: &[f64]) {
+        let n = a.get_rows();
+        let eigen = a.symmetric_eigen();
+        let v = eigen.eigenvectors();
+        let mut vt = v.clone();
+        vt.transpose();
+
+        for (x, y) in eigen.eigenvalues().iter().zip(values) {
+            assert!((x - y).abs() < 1e-10);
+        }
+        assert!((vt.clone() * v.clone() - CMatrix::identity(n, n)).norm() < 1e-10);
+
+        let mut lambda = CMatrix::zero(n, n);
+        for (i, &x) in eigen.eigenvalues().iter().enumerate() {
+            lambda[(i, i)] = x;
+        }
+        assert!((v * lambda * vt - a).norm() < 1e-10);
+    }
+
+    #[test]
+    fn symmetric_eigen_matrix3_test() {
+        let m = Matrix3::new(2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0);
+        let s = 2f64.sqrt();
+
+        check(m.to_f64(), &[2.0 - s, 2.0, 2.0 + s]);
+        assert_eq!(Matrix2::new(3, 0, 0, 1).symmetric_eigen().eigenvalues(), &[1.0, 3.0]);
+    }
+
+    #[test]
+    fn symmetric_eigen_cmatrix_test() {
+        let a: CMatrix<f64> = cmat![
+            4.0, 1.0, -2.0, 2.0;
+            1.0, 2.0, 0.0, 1.0;
+            -2.0, 0.0, 3.0, -2.0;
+            2.0, 1.0, -2.0, -1.0
+        ];
+        let eigen = a.symmetric_eigen();
+        let trace: f64 = eigen.eigenvalues().iter().sum();
+
+        assert!((trace - 8.0).abs() < 1e-10);
+        assert!(eigen.eigenvalues().windows(2).all(|w| w[0] <= w[1]));
+        check(a.clone(), eigen.eigenvalues());
+    }
+
+    #[test]
+    fn symmetric_eigen_diagonal_test() {
+        let mut a = CMatrix::<f64>::zero(5, 5);
+        for i in 0..5 {
+            a[(i, i)] = (5 - i) as f64;
+        }
+
+        check(a, &[1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn symmetric_eigen_errors_test() {
+        assert!(matches!(Matrix2::new(1, 2, 3, 4).try_symmetric_eigen(), Err(MatrixError::NotSymmetric)));
+        assert!(matches!(CMatrix::<f64>::one(2, 3).try_symmetric_eigen(), Err(MatrixError::NotSquare { .. })));
+    }
+
+    #[test]
+    fn eigen_non_finite_test() {
+        let mut small = CMatrix::<f64>::identity(2, 2);
+        small[(1, 1)] = f64::NAN;
+        let mut big = CMatrix::<f64>::identity(5, 5);
+        big[(2, 2)] = f64::INFINITY;
+        big[(1, 2)] = 1.0;
+        big[(2, 1)] = 1.0;
+        let mut general = CMatrix::<f64>::one(5, 5);
+        general[(2, 3)] = f64::NAN;
+
+        assert!(matches!(small.try_symmetric_eigen(), Err(MatrixError::NoConvergence)));
+        assert!(matches!(big.try_symmetric_eigen(), Err(MatrixError::NoConvergence)));
+        assert!(matches!(general.try_eigenvalues(), Err(MatrixError::NoConvergence)));
+        assert!(matches!(general.try_schur(), Err(MatrixError::NoConvergence)));
+    }
+
+    #[test]
+    fn eigenvalues_complex_test() {
+        // rotation by 90 degrees around z axis, scaled z
+        let m = Matrix3::new(0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 2.0);
+        let mut values = m.eigenvalues();
+        values.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+
+        let expected = [Complex::new(0.0, -1.0), Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)];
+        for (x, y) in values.iter().zip(expected.iter()) {
+            assert!((x - y).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn schur_test() {
+        let a: CMatrix<f64> = cmat![
+            1.0, 2.0, 3.0, 4.0;
+            -1.0, 0.0, 5.0, 1.0;
+            2.0, -3.0, 1.0, 0.0;
+            0.0, 1.0, -2.0, 3.0
+        ];
+        let schur = a.schur();
+        let (t, z) = (schur.t(), schur.z());
+        let mut zt = z.clone();
+        zt.transpose();
+
+        assert!((z.clone() * t.clone() * zt.clone() - a.clone()).norm() < 1e-10);
+        assert!((zt * z - CMatrix::identity(4, 4)).norm() < 1e-12);
+        assert!((0..4usize).all(|i| (0..i.saturating_sub(1)).all(|j| t[(i, j)] == 0.0)));
+
+        let trace: Complex<f64> = schur.eigenvalues().iter().sum();
+        assert!((trace - Complex::new(5.0, 0.0)).norm() < 1e-10);
+    }
+
+    #[test]
+    fn eigenvectors_test() {
+        let a: CMatrix<f64> = cmat![2.0, 0.0, 0.0; 1.0, 3.0, 0.0; 0.0, -1.0, 0.0];
+        let m = cmat![0.0, -2.0; 1.0, 2.0];
+
+        for a in [a, m] {
+            let n = a.get_rows();
+            for (lambda, x) in a.eigenvalues().iter().zip(a.eigenvectors()) {
+                for i in 0..n {
+                    let ax: Complex<f64> = (0..n).map(|j| x[j] * a[(i, j)]).sum();
+                    assert!((ax - lambda * x[i]).norm() < 1e-8);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn eigenvectors_repeated_test() {
+        let a: CMatrix<f64> = cmat![2.0, 0.0, 1.0; 0.0, 2.0, 1.0; 0.0, 0.0, 5.0];
+        let swap = cmat![0.0, 1.0; 1.0, 0.0];
+
+        for a in [a, swap, CMatrix::identity(3, 3)] {
+            let n = a.get_rows();
+            let (values, vectors) = (a.eigenvalues(), a.eigenvectors());
+
+            for (lambda, x) in values.iter().zip(vectors.iter()) {
+                for i in 0..n {
+                    let ax: Complex<f64> = (0..n).map(|j| x[j] * a[(i, j)]).sum();
+                    assert!((ax - lambda * x[i]).norm() < 1e-8);
+                }
+            }
+            for i in 0..n {
+                for j in 0..i {
+                    if (values[i] - values[j]).norm() < 1e-8 {
+                        let dot: Complex<f64> = vectors[i].iter().zip(vectors[j].iter()).map(|(x, y)| x.conj() * y).sum();
+                        assert!(dot.norm() < 1e-8);
+                    }
+                }
+            }
+        }
+
+        let defective: CMatrix<f64> = cmat![1.0, 1.0; 0.0, 1.0];
+        assert!(matches!(defective.try_eigenvectors(), Err(MatrixError::NoConvergence)));
+    }
+
+    #[test]
+    fn eigenvalues_not_square_test() {
+        assert!(matches!(CMatrix::<f64>::one(2, 3).try_eigenvalues(), Err(MatrixError::NotSquare { rows: 2, columns: 3 })));
+    }
+}
@@ -1,14 +1,11 @@
 extern crate mematrica;
-extern crate mematrica;
 use mematrica::*;
 
 fn main() {
-    let mut m = Matrix3::from_element(4); // creates matrix3
-    m[0].push(3); // adds '3' to first row of matrix
-    //m.det(); // panic! because m now is not square and seems like this: Matrix3 { rows: 3, columns: 3, elems: [[4, 4, 4, 3], [4, 4, 4], [4, 4, 4]] }
-    // to find determinant you need to write something like that
+    let m = Matrix3::from_element(4); // creates matrix3
+    // Matrix3 always stays 3x3, so to change its size convert it to CMatrix first
     let mut m = m.to_cmatrix();
-    m.push(vec![1,2,3,4]);
+    m.push(vec![1, 2, 3, 4]); // adds a row; shorter rows are padded with zeros, so m is now 4x4
     let m = m.to_f64();
     m.det();
-}
\ No newline at end of file
+}
@@ -6,7 +6,7 @@ fn main() {
         // read from file/write to file
 
         let m = Matrix2::new(1, 2, 3, 4);
-        m.to_file(String::from("./src/examples/file.txt"), ','); // in file now: 1,2,3,4
+        m.to_file(String::from("./src/examples/file.txt"), ','); // in file now: "# 2 2", "1,2", "3,4" on separate lines
     
         let q = Matrix2::<i32>::from_file(String::from("./src/examples/file.txt"), ',');
     
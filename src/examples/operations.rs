@@ -17,5 +17,11 @@ fn main() {
     let w = Matrix3::from_element(4);
     // also you can use try_multiplicate:
     let mul = m.try_multiplicate(w).unwrap_err();
-    assert_eq!(mul.0, String::from("Can't multiplicate this matrices: self.columns != rhs.rows"));
+    match mul {
+        MatrixError::DimensionMismatch { expected, found } => {
+            assert_eq!(expected, (2, 3));
+            assert_eq!(found, (3, 3));
+        }
+        _ => panic!("unexpected error: {}", mul),
+    }
 }
\ No newline at end of file
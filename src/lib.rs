@@ -14,28 +14,72 @@
 //! assert_eq!(vec![vec![2, 4], vec![6, 8]], double.get_elements());
 //!
 //! ```
+#![allow(clippy::module_inception)]
+
 mod matrix23_trait; 
 mod cmatrix_trait;
 mod matrix;
+/// Scalar traits for matrix elements
+pub mod scalar;
 /// Matrix 2x2
 pub mod matrix2; 
 /// Matrix 3x3
 pub mod matrix3;
+/// Matrix 4x4
+pub mod matrix4;
+/// Matrix of size known at compile time
+pub mod smatrix;
 /// Matrix of custom size
 pub mod cmatrix;
+/// Column vectors of fixed and custom size
+pub mod vector;
+/// LU decomposition
+pub mod lu;
+/// QR decomposition
+pub mod qr;
+/// Cholesky and LDL^T decompositions
+pub mod cholesky;
+/// Singular value decomposition
+pub mod svd;
+/// Eigenvalue decompositions
+pub mod eigen;
+/// Hermite and Smith normal forms of integer matrices
+pub mod normal_form;
+/// Reading and writing CSV
+pub mod csv;
+/// Reading and writing Matrix Market files
+pub mod matrix_market;
+/// Reading and writing NumPy .npy and .npz files
+pub mod npy;
+/// Serde support for matrices, enabled by `serde` feature
+#[cfg(feature = "serde")]
+pub mod serialization;
 
-/// Implementations of operations with matrix3
-pub mod matrix3_op;
-/// Implementations of operations with matrix2
-pub mod matrix2_op;
+/// Implementations of operations with smatrix
+pub mod smatrix_op;
 /// Implementations of operations with cmatrix
 pub mod cmatrix_op;
+/// Implementations of operations with vectors
+pub mod vector_op;
 /// Macro for creations of matrices
 pub mod macro_def;
 
 pub use matrix23_trait::matrix23::Matrix23;
 pub use cmatrix_trait::cmatrix_trait::CMatrixTrait;
 pub use matrix::matrix::*;
+pub use scalar::scalar::*;
 pub use cmatrix::cmatrix::*;
+pub use smatrix::smatrix::*;
+pub use vector::vector::*;
+pub use lu::lu::*;
+pub use qr::qr::*;
+pub use cholesky::cholesky::*;
+pub use svd::svd::*;
+pub use eigen::eigen::*;
+pub use normal_form::normal_form::*;
+pub use csv::csv::*;
+pub use matrix_market::matrix_market::*;
+pub use npy::npy::NpyElement;
+pub use matrix4::matrix4::*;
 pub use matrix3::matrix3::*;
 pub use matrix2::matrix2::*;
\ No newline at end of file
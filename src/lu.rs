@@ -0,0 +1,289 @@
+pub mod lu {
+    use crate::matrix::matrix::{is_negligible, zero_tolerance};
+    use crate::{CMatrix, CMatrixTrait, MatrixError, Matrix, Scalar};
+
+    /// LU decomposition with partial pivoting: P * A = L * U.
+    /// L is unit lower triangular, U is upper triangular. Both are kept packed in one buffer
+    #[derive(Debug, Clone)]
+    pub struct LU<T: Scalar> {
+        pub(crate) n: usize,
+        pub(crate) lu: Vec<T>,
+        pub(crate) perm: Vec<usize>,
+        pub(crate) swaps: usize,
+        pub(crate) tol: f64,
+    }
+
+    impl<T: Scalar> LU<T> {
+        /// Factorizes a square row-major buffer of size n x n
+        pub(crate) fn factorize(mut lu: Vec<T>, n: usize) -> LU<T> {
+            let tol = zero_tolerance(&lu, n, n);
+            let mut perm: Vec<usize> = (0..n).collect();
+            let mut swaps = 0;
+
+            for k in 0..n {
+                // Pivot is the biggest nonzero element, so exact types without order still get a nonzero pivot
+                let mut max_row = k;
+                let mut max = None;
+                for i in k..n {
+                    let e = &lu[i * n + k];
+                    if !e.is_zero() && max.map_or(true, |m| e.modulus() > m) {
+                        max_row = i;
+                        max = Some(e.modulus());
+                    }
+                }
+
+                if max_row != k {
+                    for j in 0..n {
+                        lu.swap(k * n + j, max_row * n + j);
+                    }
+                    perm.swap(k, max_row);
+                    swaps += 1;
+                }
+
+                let pivot = lu[k * n + k].clone();
+                if pivot.is_zero() {
+                    continue;
+                }
+
+                for i in k + 1..n {
+                    let factor = lu[i * n + k].clone() / pivot.clone();
+                    lu[i * n + k] = factor.clone();
+
+                    for j in k + 1..n {
+                        lu[i * n + j] = lu[i * n + j].clone() - factor.clone() * lu[k * n + j].clone();
+                    }
+                }
+            }
+
+            LU { n, lu, perm, swaps, tol }
+        }
+
+        /// Returns size of factorized matrix
+        pub fn size(&self) -> usize {
+            self.n
+        }
+
+        /// Returns row permutation: row i of P * A is row `permutation()[i]` of A
+        pub fn permutation(&self) -> &[usize] {
+            &self.perm
+        }
+
+        /// Returns permutation matrix P
+        pub fn p(&self) -> CMatrix<T> {
+            let mut p = CMatrix::zero(self.n, self.n);
+
+            for (i, &j) in self.perm.iter().enumerate() {
+                p[(i, j)] = T::one();
+            }
+            p
+        }
+
+        /// Returns unit lower triangular matrix L
+        pub fn l(&self) -> CMatrix<T> {
+            let n = self.n;
+            let mut l = CMatrix::identity(n, n);
+
+            for i in 0..n {
+                l[i][..i].clone_from_slice(&self.lu[i * n..i * n + i]);
+            }
+            l
+        }
+
+        /// Returns upper triangular matrix U
+        pub fn u(&self) -> CMatrix<T> {
+            let n = self.n;
+            let mut u = CMatrix::zero(n, n);
+
+            for i in 0..n {
+                u[i][i..].clone_from_slice(&self.lu[i * n + i..(i + 1) * n]);
+            }
+            u
+        }
+
+        /// Checks if factorized matrix is singular. Float pivots are compared with the same relative tolerance as in `rank`
+        pub fn is_singular(&self) -> bool {
+            (0..self.n).any(|i| is_negligible(&self.lu[i * self.n + i], self.tol))
+        }
+
+        /// Counts determinant of factorized matrix
+        pub fn det(&self) -> T {
+            let det = (0..self.n).fold(T::one(), |acc, i| acc * self.lu[i * self.n + i].clone());
+
+            if self.swaps % 2 == 1 {
+                T::zero() - det
+            } else {
+                det
+            }
+        }
+
+        /// Solves A * X = B, where B is a vector or a matrix with any amount of columns
+        pub fn solve<B: Matrix<T> + Clone>(&self, rhs: &B) -> B {
+            match self.try_solve(rhs) {
+                Ok(x) => x,
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        /// Try to solve A * X = B, where B is a vector or a matrix with any amount of columns
+        pub fn try_solve<B: Matrix<T> + Clone>(&self, rhs: &B) -> Result<B, MatrixError> {
+            if rhs.get_rows() != self.n {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (self.n, rhs.get_columns()),
+                    found: (rhs.get_rows(), rhs.get_columns()),
+                });
+            }
+            if self.is_singular() {
+                return Err(MatrixError::Singular);
+            }
+
+            let mut x = rhs.clone();
+            let k = rhs.get_columns();
+            let b = rhs.as_slice();
+            let out = x.as_mut_slice();
+
+            for (i, &p) in self.perm.iter().enumerate() {
+                out[i * k..(i + 1) * k].clone_from_slice(&b[p * k..(p + 1) * k]);
+            }
+
+            self.substitute(out, k);
+            Ok(x)
+        }
+
+        /// Counts inversed matrix
+        pub fn inverse(&self) -> CMatrix<T> {
+            match self.try_inverse() {
+                Ok(inv) => inv,
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        /// Try to count inversed matrix
+        pub fn try_inverse(&self) -> Result<CMatrix<T>, MatrixError> {
+            if self.is_singular() {
+                return Err(MatrixError::Singular);
+            }
+
+            let mut inv = self.p();
+            self.substitute(inv.as_mut_slice(), self.n);
+            Ok(inv)
+        }
+
+        /// Solves L * U * X = B in place for permuted B with k columns
+        fn substitute(&self, x: &mut [T], k: usize) {
+            let n = self.n;
+
+            for i in 0..n {
+                for j in 0..i {
+                    let l = &self.lu[i * n + j];
+                    for c in 0..k {
+                        x[i * k + c] = x[i * k + c].clone() - l.clone() * x[j * k + c].clone();
+                    }
+                }
+            }
+
+            for i in (0..n).rev() {
+                for j in i + 1..n {
+                    let u = &self.lu[i * n + j];
+                    for c in 0..k {
+                        x[i * k + c] = x[i * k + c].clone() - u.clone() * x[j * k + c].clone();
+                    }
+                }
+                let pivot = &self.lu[i * n + i];
+                for c in 0..k {
+                    x[i * k + c] = x[i * k + c].clone() / pivot.clone();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate num;
+
+    use self::num::Complex;
+    use crate::{cmat, CMatrix, CMatrixTrait, CVector, Matrix, Matrix3, MatrixError};
+
+    #[test]
+    fn lu_factors_test() {
+        let a: CMatrix<f64> = cmat![2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0];
+        let lu = a.lu();
+
+        assert_eq!(lu.p() * a, lu.l() * lu.u());
+        assert_eq!(lu.det(), -16.0);
+    }
+
+    #[test]
+    fn lu_solve_test() {
+        let a = Matrix3::new(2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0);
+        let lu = a.lu();
+
+        let x = lu.solve(&CVector::new(vec![5.0, -2.0, 9.0]));
+        assert_eq!(x, CVector::new(vec![1.0, 1.0, 2.0]));
+
+        let b: CMatrix<f64> = cmat![5.0, 4.0; -2.0, 4.0; 9.0, 2.0];
+        let x = lu.solve(&b);
+        assert_eq!(x, cmat![1.0, 1.0; 1.0, 0.0; 2.0, 2.0]);
+    }
+
+    #[test]
+    fn lu_singular_test() {
+        let a: CMatrix<f64> = cmat![1.0, 2.0; 2.0, 4.0];
+        let lu = a.lu();
+
+        assert!(lu.is_singular());
+        assert_eq!(lu.det(), 0.0);
+        assert!(lu.try_inverse().is_err());
+        assert!(lu.try_solve(&CVector::new(vec![1.0, 2.0])).is_err());
+    }
+
+    #[test]
+    fn lu_not_square_test() {
+        let a = CMatrix::<f64>::one(2, 3);
+
+        assert!(a.try_lu().is_err());
+    }
+
+    #[test]
+    fn lu_inverse_test() {
+        let a: CMatrix<f64> = cmat![4.0, 7.0; 2.0, 6.0];
+        let inv = a.lu().inverse();
+
+        assert!((inv - cmat![0.6, -0.7; -0.2, 0.4]).norm() < 1e-12);
+    }
+
+    #[test]
+    fn lu_complex_test() {
+        let c = |re: f64, im: f64| Complex::new(re, im);
+        let a: CMatrix<Complex<f64>> = cmat![c(1.0, 0.0), c(0.0, 2.0); c(0.0, -1.0), c(3.0, 1.0)];
+        let lu = a.lu();
+
+        assert!((lu.p() * a.clone() - lu.l() * lu.u()).norm() < 1e-12);
+        assert!((lu.det() - c(1.0, 1.0)).norm() < 1e-12);
+
+        let x = lu.solve(&CVector::new(vec![c(1.0, 2.0), c(3.0, 0.0)]));
+        assert!((a * x - CVector::new(vec![c(1.0, 2.0), c(3.0, 0.0)])).norm() < 1e-12);
+    }
+
+    #[test]
+    fn lu_integer_matrix_test() {
+        // integer LU would need truncating division, so integers are factorized through exact types
+        let a: CMatrix<i64> = cmat![2, 1; 7, 4];
+        let lu = a.to_f64().lu();
+
+        assert_eq!(a.det(), 1);
+        assert!((lu.det() - 1.0).abs() < 1e-12);
+        assert!((lu.inverse() - cmat![4.0, -1.0; -7.0, 2.0]).norm() < 1e-12);
+    }
+
+    #[test]
+    fn lu_float_singular_test() {
+        let a: CMatrix<f64> = cmat![1.0, 2.0, 3.0, 4.0; 5.0, 6.0, 7.0, 8.0; 9.0, 10.0, 11.0, 12.0; 13.0, 14.0, 15.0, 16.0];
+        let mut b: CMatrix<f64> = cmat![0.1, 0.2, 0.3; 0.4, 0.5, 0.6; 0.7, 0.8, 0.9];
+
+        assert!(a.lu().is_singular());
+        assert!(matches!(a.try_solve(&CVector::new(vec![1.0; 4])), Err(MatrixError::Singular)));
+        assert!(matches!(b.try_inverse(), Err(MatrixError::Singular)));
+        assert_eq!(b.lu().is_singular(), !b.is_invertible());
+    }
+}
@@ -12,35 +12,27 @@ macro_rules! mat3 {
     };
 }
 
+#[macro_export]
+macro_rules! mat4 {
+    [$m1: expr, $m2: expr, $m3: expr, $m4: expr, $m5: expr, $m6: expr, $m7: expr, $m8: expr, $m9: expr, $m10: expr, $m11: expr, $m12: expr, $m13: expr, $m14: expr, $m15: expr, $m16: expr] => {
+        Matrix4::new($m1, $m2, $m3, $m4, $m5, $m6, $m7, $m8, $m9, $m10, $m11, $m12, $m13, $m14, $m15, $m16)
+    };
+}
+
 #[macro_export]
 macro_rules! cmat {
     ($( $( $x: expr ),* ); *) => {
         {
-        let mut e = vec![];
-        let mut r = 0;
-        let mut c = 0;
-        let mut f = true;
-        $(
-            let mut v = vec![];
-            let mut t = 0;
-            $(
-                v.push($x);
-                t += 1;
-                if f {
-                    c += 1;
-                }
-            )*
-            f = false;
-            assert_eq!(t, c);
-            t = 0;
-            c = v.len();
-            e.push(v);
-            r += 1;
-        )*
+        let e = vec![$( vec![$( $x ),*] ),*];
+
+        let c = e.first().map_or(0, |v: &Vec<_>| v.len());
+        for v in e.iter() {
+            assert_eq!(v.len(), c);
+        }
 
-        let mut c: CMatrix<_> = CMatrix::zero(r, c);
+        let mut c: CMatrix<_> = CMatrix::zero(e.len(), c);
         c.set_elements(e);
         c
     }
     };
-}
\ No newline at end of file
+}
@@ -1,51 +1,549 @@
 pub mod matrix {
     extern crate num;
 
-    use self::num::Num;
+    use self::num::Complex;
+    use crate::{ComplexField, DivisionKind, Field, Real, Scalar};
     pub use std::ops::Add;
+    use std::fs::File;
+    use std::io::{BufWriter, Write};
+    use std::ops::Neg;
+    use std::path::Path;
+
+    use crate::cholesky::cholesky::is_symmetric;
+    use crate::eigen::eigen::inverse_iteration;
+    use crate::{CMatrix, CMatrixTrait, Cholesky, RealSchur, SymmetricEigen, LDLT, LU, QR, SVD};
+
+    /// An error returned by fallible matrix operations
+    #[derive(Debug)]
+    #[non_exhaustive]
+    pub enum MatrixError {
+        /// Sizes of operands don't match. Sizes are given as (rows, columns)
+        DimensionMismatch {
+            expected: (usize, usize),
+            found: (usize, usize),
+        },
+        /// Matrix is singular or rank deficient
+        Singular,
+        /// Operation needs square matrix
+        NotSquare { rows: usize, columns: usize },
+        /// Operation needs symmetric matrix
+        NotSymmetric,
+        /// Operation needs positive definite matrix
+        NotPositiveDefinite,
+        /// Index is bigger than or equal to its bound
+        IndexOutOfBounds { index: usize, bound: usize },
+        /// Iterative algorithm didn't converge
+        NoConvergence,
+        /// Reading or writing failed
+        Io(std::io::Error),
+        /// Row has another amount of elements than previous rows. Line and column are counted from 1
+        RaggedRow {
+            line: usize,
+            column: usize,
+            expected: usize,
+            found: usize,
+        },
+        /// Token can't be parsed. Line and column are counted from 1
+        Parse {
+            line: usize,
+            column: usize,
+            token: String,
+        },
+        /// Column with given name isn't found in header
+        ColumnNotFound(String),
+        /// Input doesn't follow file format. Line is counted from 1
+        Format { line: usize, message: String },
+        /// Data isn't valid .npy array or .npz archive
+        InvalidNpy(String),
+        /// Element type of stored array differs from the requested one. Types are given as NumPy descriptors, e.g. `<f8`
+        DtypeMismatch { expected: String, found: String },
+    }
 
-    use crate::{CMatrix, CMatrixTrait};
+    impl std::fmt::Display for MatrixError {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            match self {
+                MatrixError::DimensionMismatch { expected, found } => write!(
+                    formatter,
+                    "Dimension mismatch: expected {}x{}, found {}x{}",
+                    expected.0, expected.1, found.0, found.1
+                ),
+                MatrixError::Singular => write!(formatter, "Matrix is singular"),
+                MatrixError::NotSquare { rows, columns } => {
+                    write!(formatter, "Matrix is not square: {}x{}", rows, columns)
+                }
+                MatrixError::NotSymmetric => write!(formatter, "Matrix is not symmetric"),
+                MatrixError::NotPositiveDefinite => {
+                    write!(formatter, "Matrix is not positive definite")
+                }
+                MatrixError::IndexOutOfBounds { index, bound } => {
+                    write!(formatter, "Index {} is out of bounds {}", index, bound)
+                }
+                MatrixError::NoConvergence => write!(formatter, "Iterations didn't converge"),
+                MatrixError::Io(e) => write!(formatter, "I/O error: {}", e),
+                MatrixError::RaggedRow {
+                    line,
+                    column,
+                    expected,
+                    found,
+                } => write!(
+                    formatter,
+                    "Row at line {} has {} elements instead of {} (column {})",
+                    line, found, expected, column
+                ),
+                MatrixError::Parse {
+                    line,
+                    column,
+                    token,
+                } => write!(
+                    formatter,
+                    "Can't parse '{}' at line {}, column {}",
+                    token, line, column
+                ),
+                MatrixError::ColumnNotFound(name) => write!(formatter, "Column '{}' is not found", name),
+                MatrixError::Format { line, message } => {
+                    write!(formatter, "Wrong format at line {}: {}", line, message)
+                }
+                MatrixError::InvalidNpy(message) => write!(formatter, "Invalid NumPy data: {}", message),
+                MatrixError::DtypeMismatch { expected, found } => {
+                    write!(formatter, "Dtype mismatch: expected {}, found {}", expected, found)
+                }
+            }
+        }
+    }
 
-    /// An error
-    #[derive(Clone, Debug)]
-    pub struct Error(pub String);
+    impl std::error::Error for MatrixError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match self {
+                MatrixError::Io(e) => Some(e),
+                _ => None,
+            }
+        }
+    }
 
-    impl std::fmt::Display for Error {
-        #[inline]
-        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-            self.0.fmt(formatter)
+    impl From<std::io::Error> for MatrixError {
+        fn from(e: std::io::Error) -> Self {
+            MatrixError::Io(e)
+        }
+    }
+
+    /// Parses text format: one row per line, elements separated by `delimiter`.
+    /// Optional first line `# rows columns` declares the size, other lines starting with `#` and blank lines are skipped.
+    /// Returns (rows, columns, row-major elements)
+    pub(crate) fn parse_rows<T>(s: &str, delimiter: char) -> Result<(usize, usize, Vec<T>), MatrixError>
+    where
+        T: std::str::FromStr,
+    {
+        let mut header = None;
+        let mut columns = None;
+        let mut rows = 0;
+        let mut elems = Vec::new();
+
+        for (i, line) in s.lines().enumerate() {
+            let trimmed = line.trim();
+
+            if trimmed.is_empty() {
+                continue;
+            }
+            if let Some(comment) = trimmed.strip_prefix('#') {
+                let size: Vec<usize> = comment.split_whitespace().filter_map(|t| t.parse().ok()).collect();
+                if rows == 0 && header.is_none() && size.len() == 2 && comment.split_whitespace().count() == 2 {
+                    header = Some((size[0], size[1]));
+                    columns = Some(size[1]);
+                }
+                continue;
+            }
+
+            let tokens = split_tokens(line, delimiter);
+            let expected = *columns.get_or_insert(tokens.len());
+
+            if tokens.len() != expected {
+                let offset = match tokens.get(expected) {
+                    Some(&(offset, _)) => offset,
+                    None => line.trim_end().len(),
+                };
+                return Err(MatrixError::RaggedRow {
+                    line: i + 1,
+                    column: line[..offset].chars().count() + 1,
+                    expected,
+                    found: tokens.len(),
+                });
+            }
+
+            for (offset, token) in tokens {
+                match token.parse() {
+                    Ok(x) => elems.push(x),
+                    Err(_) => {
+                        return Err(MatrixError::Parse {
+                            line: i + 1,
+                            column: line[..offset].chars().count() + 1,
+                            token: token.to_string(),
+                        })
+                    }
+                }
+            }
+            rows += 1;
+        }
+
+        let columns = columns.unwrap_or(0);
+        if let Some(size) = header {
+            if size != (rows, columns) {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: size,
+                    found: (rows, columns),
+                });
+            }
+        }
+
+        Ok((rows, columns, elems))
+    }
+
+    /// Splits line into trimmed tokens with their byte offsets. Whitespace delimiter matches any amount of whitespace
+    pub(crate) fn split_tokens(line: &str, delimiter: char) -> Vec<(usize, &str)> {
+        let mut tokens = Vec::new();
+
+        if delimiter.is_whitespace() {
+            let mut start = None;
+            for (i, c) in line.char_indices() {
+                match (c.is_whitespace(), start) {
+                    (false, None) => start = Some(i),
+                    (true, Some(s)) => {
+                        tokens.push((s, &line[s..i]));
+                        start = None;
+                    }
+                    _ => (),
+                }
+            }
+            if let Some(s) = start {
+                tokens.push((s, &line[s..]));
+            }
+        } else {
+            let mut offset = 0;
+            for token in line.split(delimiter) {
+                let lead = token.len() - token.trim_start().len();
+                tokens.push((offset + lead, token.trim()));
+                offset += token.len() + delimiter.len_utf8();
+            }
+        }
+        tokens
+    }
+
+    /// Flattens rows into a row-major buffer. Rows shorter than the longest one are padded with zeros
+    pub(crate) fn flatten_rows<T: Scalar>(v: Vec<Vec<T>>) -> (usize, usize, Vec<T>) {
+        let rows = v.len();
+        let columns = v.iter().map(|r| r.len()).max().unwrap_or(0);
+        let mut elems = Vec::with_capacity(rows * columns);
+
+        for mut row in v {
+            row.resize(columns, T::zero());
+            elems.extend(row);
+        }
+
+        (rows, columns, elems)
+    }
+
+    /// Finds determinant of a square row-major buffer with Bareiss fraction-free elimination.
+    /// Every division is exact and intermediate values are minors of matrix, so integers don't lose exactness.
+    /// Minors may be negative, so element type must be signed. Buffer is used as working storage and is overwritten
+    fn eliminate_det<T: Scalar + Neg<Output = T>>(mat: &mut [T], n: usize) -> T {
+        let mut prev = T::one();
+        let mut negative = false;
+
+        for k in 0..n {
+            let index = match (k..n).find(|&r| !mat[r * n + k].is_zero()) {
+                Some(index) => index,
+                None => return T::zero(),
+            };
+
+            if index != k {
+                for j in 0..n {
+                    mat.swap(index * n + j, k * n + j);
+                }
+                negative = !negative;
+            }
+
+            let pivot = mat[k * n + k].clone();
+
+            for i in (k + 1)..n {
+                let factor = mat[i * n + k].clone();
+
+                for j in (k + 1)..n {
+                    let minor = pivot.clone() * mat[i * n + j].clone() - factor.clone() * mat[k * n + j].clone();
+                    mat[i * n + j] = minor / prev.clone();
+                }
+                mat[i * n + k] = T::zero();
+            }
+
+            prev = pivot;
+        }
+
+        if negative {
+            -prev
+        } else {
+            prev
+        }
+    }
+
+    /// Reduces row-major buffer to reduced row echelon form with Gauss-Jordan elimination.
+    /// Elements with modulus not greater than `tol` are treated as zero. Returns indices of pivot columns
+    fn reduce_rows<T: Scalar>(a: &mut [T], rows: usize, columns: usize, tol: f64) -> Vec<usize> {
+        let mut pivots = Vec::new();
+
+        for j in 0..columns {
+            let r = pivots.len();
+            if r == rows {
+                break;
+            }
+
+            let mut max_row = None;
+            for i in r..rows {
+                let e = &a[i * columns + j];
+                if !is_negligible(e, tol) && max_row.map_or(true, |m: usize| e.modulus() > a[m * columns + j].modulus()) {
+                    max_row = Some(i);
+                }
+            }
+            let max_row = match max_row {
+                Some(max_row) => max_row,
+                None => {
+                    for i in r..rows {
+                        a[i * columns + j] = T::zero();
+                    }
+                    continue;
+                }
+            };
+
+            for c in 0..columns {
+                a.swap(r * columns + c, max_row * columns + c);
+            }
+
+            let pivot = a[r * columns + j].clone();
+            for c in j..columns {
+                a[r * columns + c] = a[r * columns + c].clone() / pivot.clone();
+            }
+
+            for i in (0..rows).filter(|&i| i != r) {
+                let factor = a[i * columns + j].clone();
+                if factor.is_zero() {
+                    continue;
+                }
+                for c in j..columns {
+                    a[i * columns + c] = a[i * columns + c].clone() - factor.clone() * a[r * columns + c].clone();
+                }
+            }
+
+            pivots.push(j);
+        }
+
+        pivots
+    }
+
+    /// Reduces row-major buffer to row echelon form with Bareiss fraction-free elimination and returns indices of pivot columns.
+    /// Every division is exact, so it works for integers. Minors may be negative, so element type must be signed
+    fn fraction_free_pivots<T: Scalar + Neg<Output = T>>(a: &mut [T], rows: usize, columns: usize) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        let mut prev = T::one();
+
+        for j in 0..columns {
+            let r = pivots.len();
+            if r == rows {
+                break;
+            }
+
+            let index = match (r..rows).find(|&i| !a[i * columns + j].is_zero()) {
+                Some(index) => index,
+                None => continue,
+            };
+
+            for c in 0..columns {
+                a.swap(r * columns + c, index * columns + c);
+            }
+
+            let pivot = a[r * columns + j].clone();
+            for i in (r + 1)..rows {
+                let factor = a[i * columns + j].clone();
+
+                for c in (j + 1)..columns {
+                    let minor = pivot.clone() * a[i * columns + c].clone() - factor.clone() * a[r * columns + c].clone();
+                    a[i * columns + c] = minor / prev.clone();
+                }
+                a[i * columns + j] = T::zero();
+            }
+
+            prev = pivot;
+            pivots.push(j);
+        }
+
+        pivots
+    }
+
+    /// Tolerance below which elements of matrix are treated as zero. Zero for exact types
+    pub(crate) fn zero_tolerance<T: Scalar>(a: &[T], rows: usize, columns: usize) -> f64 {
+        let max = a.iter().fold(0.0, |acc: f64, e| acc.max(e.modulus()));
+        max * rows.max(columns) as f64 * T::epsilon()
+    }
+
+    /// Checks if element is zero up to tolerance counted by `zero_tolerance`. Zero tolerance means exact comparison
+    pub(crate) fn is_negligible<T: Scalar>(e: &T, tol: f64) -> bool {
+        e.is_zero() || (tol > 0.0 && e.modulus() <= tol)
+    }
+
+    /// Finds pivot columns of matrix: exactly for integers and rationals, up to rounding errors for floats
+    fn pivot_columns<T: Scalar + Neg<Output = T>>(a: &[T], rows: usize, columns: usize) -> Vec<usize> {
+        let mut a = a.to_vec();
+
+        if <T::Division as DivisionKind>::EXACT {
+            let tol = zero_tolerance(&a, rows, columns);
+            reduce_rows(&mut a, rows, columns, tol)
+        } else {
+            fraction_free_pivots(&mut a, rows, columns)
+        }
+    }
+
+    /// Solves a 2x2 or 3x3 system in place with closed-form adjugate formula. `x` holds k right-hand columns.
+    /// Determinant is a product of n pivots, so it is compared with LU pivot tolerance scaled by the largest element n - 1 times
+    fn solve_closed_form<T: Field>(a: &[T], n: usize, x: &mut [T], k: usize) -> Result<(), MatrixError> {
+        let (det, adj) = if n == 2 {
+            (
+                a[0].clone() * a[3].clone() - a[1].clone() * a[2].clone(),
+                vec![a[3].clone(), T::zero() - a[1].clone(), T::zero() - a[2].clone(), a[0].clone()],
+            )
+        } else {
+            let c = |i: usize, j: usize, p: usize, q: usize| {
+                a[i * 3 + j].clone() * a[p * 3 + q].clone() - a[i * 3 + q].clone() * a[p * 3 + j].clone()
+            };
+            let adj = vec![
+                c(1, 1, 2, 2), c(0, 2, 2, 1), c(0, 1, 1, 2),
+                c(1, 2, 2, 0), c(0, 0, 2, 2), c(0, 2, 1, 0),
+                c(1, 0, 2, 1), c(0, 1, 2, 0), c(0, 0, 1, 1),
+            ];
+            let det = a[0].clone() * adj[0].clone() + a[1].clone() * adj[3].clone() + a[2].clone() * adj[6].clone();
+            (det, adj)
+        };
+
+        let max = a.iter().fold(0.0, |acc: f64, e| acc.max(e.modulus()));
+        if is_negligible(&det, zero_tolerance(a, n, n) * max.powi(n as i32 - 1)) {
+            return Err(MatrixError::Singular);
+        }
+
+        let mut column = vec![T::zero(); n];
+        for c in 0..k {
+            for (i, e) in column.iter_mut().enumerate() {
+                *e = x[i * k + c].clone();
+            }
+            for i in 0..n {
+                let sum = (0..n).fold(T::zero(), |acc, j| acc + adj[i * n + j].clone() * column[j].clone());
+                x[i * k + c] = sum / det.clone();
+            }
         }
+
+        Ok(())
     }
 
-    impl std::error::Error for Error {
-        #[inline]
-        fn description(&self) -> &str {
-            &self.0
+    /// Applies `f` to pairs of elements of matrices of the same size
+    fn zip_with<T, A, M, F>(a: &A, rhs: &M, f: F) -> Result<A, MatrixError>
+    where
+        T: Scalar,
+        A: Matrix<T> + Clone,
+        M: Matrix<T>,
+        F: Fn(T, T) -> T,
+    {
+        if a.get_rows() != rhs.get_rows() || a.get_columns() != rhs.get_columns() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (a.get_rows(), a.get_columns()),
+                found: (rhs.get_rows(), rhs.get_columns()),
+            });
         }
+
+        let mut result = a.clone();
+        for (x, y) in result.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *x = f(x.clone(), y.clone());
+        }
+        Ok(result)
     }
 
-    pub trait Matrix<T: Num + Default + Clone + Copy + PartialOrd + std::str::FromStr + std::fmt::Debug + std::convert::Into<f64>> {
+    /// Matrix types which can hold their own transpose: CMatrix and square SMatrix.
+    /// Column vectors and non-square SMatrix don't implement it, so they have no `transpose` and `adjoint`
+    /// ```compile_fail
+    /// use mematrica::*;
+    ///
+    /// let mut v = CVector::new(vec![1, 2, 3]);
+    /// v.transpose();
+    /// ```
+    pub trait Transposable {}
+
+    pub trait Matrix<T: Scalar> {
         /// Transpose matrix
         fn transpose(&mut self)
         where
-            Self: Sized,
+            Self: Sized + Transposable,
         {
             let r = self.get_rows();
             let c = self.get_columns();
-            let elems = self.get_elements();
-            let mut transposed = vec![vec![elems[0][0]; r]; c];
 
-            for (i, row) in elems.iter().enumerate() {
-                for (j, &item) in row.iter().enumerate() {
-                    transposed[j][i] = item;
+            if r == c {
+                let elems = self.as_mut_slice();
+                for i in 0..r {
+                    for j in (i + 1)..c {
+                        elems.swap(i * c + j, j * c + i);
+                    }
                 }
+                return;
             }
-        
+
+            let transposed = (0..c)
+                .map(|j| (0..r).map(|i| self.row(i)[j].clone()).collect())
+                .collect();
+
             self.set_elements(transposed);
         }
 
-        /// Changes size of matrix, if it was formated. It calls automatically
-        fn resize(&mut self);
+        /// Returns conjugate transpose of matrix. For real matrices it is the same as transpose
+        fn adjoint(&self) -> Self
+        where
+            Self: Sized + Clone + Transposable,
+            T: ComplexField,
+        {
+            let mut m = self.clone();
+            m.transpose();
+
+            for e in m.as_mut_slice().iter_mut() {
+                *e = e.conjugate();
+            }
+            m
+        }
+
+        /// Checks if square matrix is equal to its conjugate transpose up to rounding errors
+        fn is_hermitian(&self) -> bool
+        where
+            T: ComplexField,
+        {
+            let n = self.get_rows();
+            if n != self.get_columns() {
+                return false;
+            }
+
+            let a = self.as_slice();
+            let max = a.iter().fold(0.0, |acc: f64, e| acc.max(e.modulus()));
+            let tol = max * n as f64 * f64::EPSILON;
+
+            (0..n).all(|i| (0..=i).all(|j| (a[i * n + j].clone() - a[j * n + i].conjugate()).modulus() <= tol))
+        }
+
+        /// Checks if square matrix is unitary, i.e. A^H * A = I up to rounding errors. Computations are made in Complex<f64>
+        fn is_unitary(&self) -> bool
+        where
+            T: ComplexField,
+        {
+            let n = self.get_rows();
+            if n != self.get_columns() {
+                return false;
+            }
+
+            let a = self.to_complex();
+            let product = a.adjoint() * a;
+            let tol = (n * n) as f64 * 4.0 * f64::EPSILON;
+
+            (product - CMatrix::identity(n, n)).norm() <= tol
+        }
 
         /// Checks size of matrix, if it was formated. It calls automatically
         fn check_size(&self);
@@ -55,430 +553,604 @@ pub mod matrix {
         where
             M: Matrix<T>,
         {
-            if self.get_columns() != rhs.get_rows() {
-                panic!("Can't multiplicate this matrices: self.columns != rhs.rows");
+            match self.try_multiplicate(rhs) {
+                Ok(result) => result,
+                Err(e) => panic!("{}", e),
             }
+        }
 
-            let mut result = CMatrix::zero(self.get_rows(), rhs.get_columns());
+        /// Try to multiplicate matrices
+        fn try_multiplicate<M>(&self, rhs: M) -> Result<CMatrix<T>, MatrixError>
+        where
+            M: Matrix<T>,
+        {
+            self.checked_mul(&rhs)
+        }
 
-            for i in 0..self.get_rows() {
-                for j in 0..rhs.get_columns() {
-                    for k in 0..self.get_columns() {
-                        result[i][j] = result[i][j] + self.get_elements()[i][k] * rhs.get_elements()[k][j];
-                    }
-                }
-            }
-        
-            result
+        /// Adds matrices of the same size. Unlike `+`, returns an error instead of panic
+        fn checked_add<M>(&self, rhs: &M) -> Result<Self, MatrixError>
+        where
+            Self: Sized + Clone,
+            M: Matrix<T>,
+        {
+            zip_with(self, rhs, |a, b| a + b)
         }
 
-        /// Try to multiplicate matrices
-        fn try_multiplicate<M>(&self, rhs: M) -> Result<CMatrix<T>, Error>
+        /// Subtracts matrices of the same size. Unlike `-`, returns an error instead of panic
+        fn checked_sub<M>(&self, rhs: &M) -> Result<Self, MatrixError>
+        where
+            Self: Sized + Clone,
+            M: Matrix<T>,
+        {
+            zip_with(self, rhs, |a, b| a - b)
+        }
+
+        /// Multiplies matrices. Unlike `*`, returns an error instead of panic
+        fn checked_mul<M>(&self, rhs: &M) -> Result<CMatrix<T>, MatrixError>
         where
             M: Matrix<T>,
         {
             if self.get_columns() != rhs.get_rows() {
-                return Err(Error(String::from(
-                    "Can't multiplicate this matrices: self.columns != rhs.rows",
-                )));
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (self.get_columns(), rhs.get_columns()),
+                    found: (rhs.get_rows(), rhs.get_columns()),
+                });
             }
 
-            let mut result = CMatrix::zero(self.get_rows(), rhs.get_columns());
+            let n = self.get_rows();
+            let m = self.get_columns();
+            let p = rhs.get_columns();
+            let a = self.as_slice();
+            let b = rhs.as_slice();
 
-            for i in 0..self.get_rows() {
-                for j in 0..rhs.get_columns() {
-                    for k in 0..self.get_columns() {
-                        result[i][j] = result[i][j] + self.get_elements()[i][k] * rhs.get_elements()[k][j];
+            let mut result = CMatrix::<T>::zero(n, p);
+            let out = result.as_mut_slice();
+
+            for i in 0..n {
+                for k in 0..m {
+                    let aik = &a[i * m + k];
+                    for j in 0..p {
+                        out[i * p + j] = out[i * p + j].clone() + aik.clone() * b[k * p + j].clone();
                     }
                 }
             }
-        
+
             Ok(result)
         }
 
-        /// Counts determinant of matrix
-        fn det(&self) -> T {
+        /// Counts determinant of matrix. Element type must be signed, because determinant may be negative,
+        /// so unsigned matrices have to be converted to a signed type first
+        fn det(&self) -> T
+        where
+            T: Neg<Output = T>,
+        {
             self.check_size();
+            match self.try_det() {
+                Ok(det) => det,
+                Err(e) => panic!("{}", e),
+            }
+        }
+        /// Try to find determinant of matrix
+        fn try_det(&self) -> Result<T, MatrixError>
+        where
+            T: Neg<Output = T>,
+        {
             if self.get_rows() != self.get_columns() {
-                panic!("Can't find determinant! Maybe rows != columns?");
+                return Err(MatrixError::NotSquare {
+                    rows: self.get_rows(),
+                    columns: self.get_columns(),
+                });
             }
 
-            let mut mat = self.get_elements();
-            let elems = self.get_elements();
-            let mut temp = vec![];
-            let mut total = T::one();
-            let mut det = T::one();
+            if <T::Division as DivisionKind>::EXACT {
+                return Ok(LU::factorize(self.as_slice().to_vec(), self.get_rows()).det());
+            }
 
-            let n = self.get_rows();
+            let mut mat = self.as_slice().to_vec();
 
-            for _ in 0..n {
-                temp.push(T::zero());
+            Ok(eliminate_det(&mut mat, self.get_rows()))
+        }
+
+        /// Counts inversed matrix
+        fn inverse(&mut self)
+        where
+            T: Field,
+        {
+            if let Err(e) = self.try_inverse() {
+                panic!("{}", e);
             }
+            self.check_size();
+        }
 
-            for i in 0..n {
-                let mut index = i;
-                while index < n && elems[index][i] == T::zero() {
-                    index += 1;
-                }
+        /// Try to count inversed matrix
+        fn try_inverse(&mut self) -> Result<(), MatrixError>
+        where
+            T: Field,
+        {
+            if self.get_rows() != self.get_columns() {
+                return Err(MatrixError::NotSquare {
+                    rows: self.get_rows(),
+                    columns: self.get_columns(),
+                });
+            }
 
-                if index == n {
-                    continue;
-                }
+            let inv = self.try_lu()?.try_inverse()?;
 
-                if index != i {
-                    for j in 0..n {
-                        (mat[index][j], mat[i][j]) = (mat[i][j].clone(), mat[index][j].clone());
-                    }
+            self.as_mut_slice().clone_from_slice(inv.as_slice());
+            Ok(())
+        }
 
-                    let exp = index - i;
-                    if exp % 2 == 1 {
-                        det = det * (T::zero() - T::one());
-                    }
-                }
+        /// Counts reduced row echelon form with Gauss-Jordan elimination and returns it with indices of pivot columns.
+        /// Exact types like Ratio<BigInt> are compared with zero exactly, floats up to rounding errors
+        fn rref(&self) -> (Self, Vec<usize>)
+        where
+            Self: Sized + Clone,
+            T: Field,
+        {
+            let mut m = self.clone();
+            let (rows, columns) = (self.get_rows(), self.get_columns());
+            let tol = zero_tolerance(self.as_slice(), rows, columns);
 
-                for j in 0..n {
-                    temp[j] = mat[i][j].clone();
-                }
+            let pivots = reduce_rows(m.as_mut_slice(), rows, columns, tol);
+            (m, pivots)
+        }
 
-                for j in (i + 1)..n {
-                    let num1 = temp[i].clone();
-                    let num2 = mat[j][i].clone();
+        /// Counts rank of matrix. Integer matrices are eliminated without fractions, so the result is exact.
+        /// Intermediate minors may be negative, so element type must be signed
+        fn rank(&self) -> usize
+        where
+            T: Neg<Output = T>,
+        {
+            pivot_columns(self.as_slice(), self.get_rows(), self.get_columns()).len()
+        }
 
-                    for k in 0..n {
-                        mat[j][k] =
-                            (num1.clone() * mat[j][k].clone()) - (num2.clone() * temp[k].clone());
-                    }
+        /// Checks if matrix is square and has full rank
+        fn is_invertible(&self) -> bool
+        where
+            T: Neg<Output = T>,
+        {
+            self.get_rows() == self.get_columns() && self.rank() == self.get_rows()
+        }
 
-                    total = total * num1;
+        /// Returns basis of null space {x: self * x = 0} as columns of matrix with `get_columns()` rows
+        fn null_space(&self) -> CMatrix<T>
+        where
+            Self: Sized + Clone,
+            T: Field,
+        {
+            let (r, pivots) = self.rref();
+            let n = self.get_columns();
+            let free: Vec<usize> = (0..n).filter(|j| !pivots.contains(j)).collect();
+            let mut basis = CMatrix::zero(n, free.len());
+
+            for (k, &f) in free.iter().enumerate() {
+                basis[(f, k)] = T::one();
+                for (i, &p) in pivots.iter().enumerate() {
+                    basis[(p, k)] = T::zero() - r.row(i)[f].clone();
                 }
             }
+            basis
+        }
 
-            for i in 0..n {
-                det = det * mat[i][i].clone();
-            }
+        /// Returns basis of column space: pivot columns of matrix
+        fn column_space(&self) -> CMatrix<T>
+        where
+            T: Neg<Output = T>,
+        {
+            let pivots = pivot_columns(self.as_slice(), self.get_rows(), self.get_columns());
+            let mut basis = CMatrix::zero(self.get_rows(), pivots.len());
 
-            if total == T::zero() {
-                total = T::one();
+            for i in 0..self.get_rows() {
+                for (k, &p) in pivots.iter().enumerate() {
+                    basis[(i, k)] = self.row(i)[p].clone();
+                }
             }
-            self.check_size();
-
-            det / total
+            basis
         }
-        /// Try to find determinant of matrix
-        fn try_det(&self) -> Result<T, Error> {
-            if self.get_rows() != self.get_columns() {
-                return Err(Error(String::from(
-                    "Can't find determinant! Maybe rows != columns?",
-                )));
+
+        /// Returns basis of row space: nonzero rows of reduced row echelon form
+        fn row_space(&self) -> CMatrix<T>
+        where
+            Self: Sized + Clone,
+            T: Field,
+        {
+            let (r, pivots) = self.rref();
+            let columns = self.get_columns();
+
+            CMatrix {
+                rows: pivots.len(),
+                columns,
+                elems: r.as_slice()[..pivots.len() * columns].to_vec(),
             }
+        }
 
-            let mut mat = self.get_elements();
-            let elems = self.get_elements();
-            let mut temp = vec![];
-            let mut total = T::one();
-            let mut det = T::one();
+        /// Solves system self * X = rhs, where rhs is a vector or a matrix with any amount of columns
+        fn solve<B>(&self, rhs: &B) -> B
+        where
+            B: Matrix<T> + Clone,
+            T: Field,
+        {
+            match self.try_solve(rhs) {
+                Ok(x) => x,
+                Err(e) => panic!("{}", e),
+            }
+        }
 
+        /// Try to solve system self * X = rhs. 2x2 and 3x3 systems are solved with closed-form formulas, others with LU decomposition
+        fn try_solve<B>(&self, rhs: &B) -> Result<B, MatrixError>
+        where
+            B: Matrix<T> + Clone,
+            T: Field,
+        {
             let n = self.get_rows();
 
-            for _ in 0..n {
-                temp.push(T::zero());
+            if n != self.get_columns() {
+                return Err(MatrixError::NotSquare {
+                    rows: self.get_rows(),
+                    columns: self.get_columns(),
+                });
+            }
+            if rhs.get_rows() != n {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (n, rhs.get_columns()),
+                    found: (rhs.get_rows(), rhs.get_columns()),
+                });
             }
 
-            for i in 0..n {
-                let mut index = i;
-                while index < n && elems[index][i] == T::zero() {
-                    index += 1;
-                }
+            if n == 2 || n == 3 {
+                let mut x = rhs.clone();
+                let k = x.get_columns();
+                solve_closed_form(self.as_slice(), n, x.as_mut_slice(), k)?;
+                return Ok(x);
+            }
 
-                if index == n {
-                    continue;
-                }
+            self.try_lu()?.try_solve(rhs)
+        }
 
-                if index != i {
-                    for j in 0..n {
-                        (mat[index][j], mat[i][j]) = (mat[i][j].clone(), mat[index][j].clone());
-                    }
+        /// Counts LU decomposition with partial pivoting. Element type must have exact division, e.g. f64 or Ratio<BigInt>
+        fn lu(&self) -> LU<T>
+        where
+            T: Field,
+        {
+            match self.try_lu() {
+                Ok(lu) => lu,
+                Err(e) => panic!("{}", e),
+            }
+        }
 
-                    let exp = index - i;
-                    if exp % 2 == 1 {
-                        det = det * (T::zero() - T::one());
-                    }
-                }
+        /// Try to count LU decomposition with partial pivoting
+        fn try_lu(&self) -> Result<LU<T>, MatrixError>
+        where
+            T: Field,
+        {
+            let n = self.get_rows();
 
-                for j in 0..n {
-                    temp[j] = mat[i][j].clone();
-                }
+            if n != self.get_columns() {
+                return Err(MatrixError::NotSquare {
+                    rows: self.get_rows(),
+                    columns: self.get_columns(),
+                });
+            }
 
-                for j in (i + 1)..n {
-                    let num1 = temp[i].clone();
-                    let num2 = mat[j][i].clone();
+            Ok(LU::factorize(self.as_slice().to_vec(), n))
+        }
 
-                    for k in 0..n {
-                        mat[j][k] =
-                            (num1.clone() * mat[j][k].clone()) - (num2.clone() * temp[k].clone());
-                    }
+        /// Counts Cholesky decomposition of symmetric positive definite matrix, computations are made in f64
+        fn cholesky(&self) -> Cholesky
+        where
+            T: Real,
+        {
+            match self.try_cholesky() {
+                Ok(ch) => ch,
+                Err(e) => panic!("{}", e),
+            }
+        }
 
-                    total = total * num1;
-                }
+        /// Try to count Cholesky decomposition of symmetric positive definite matrix
+        fn try_cholesky(&self) -> Result<Cholesky, MatrixError>
+        where
+            T: Real,
+        {
+            let n = self.get_rows();
+
+            if n != self.get_columns() {
+                return Err(MatrixError::NotSquare {
+                    rows: self.get_rows(),
+                    columns: self.get_columns(),
+                });
             }
 
-            for i in 0..n {
-                det = det * mat[i][i].clone();
+            Cholesky::factorize(self.to_f64().into_elements(), n)
+        }
+
+        /// Counts LDL^T decomposition of symmetric matrix, computations are made in f64.
+        /// Needs positive definite or quasi-definite matrix, because there is no pivoting
+        fn ldlt(&self) -> LDLT
+        where
+            T: Real,
+        {
+            match self.try_ldlt() {
+                Ok(ldlt) => ldlt,
+                Err(e) => panic!("{}", e),
             }
+        }
+
+        /// Try to count LDL^T decomposition of symmetric matrix
+        fn try_ldlt(&self) -> Result<LDLT, MatrixError>
+        where
+            T: Real,
+        {
+            let n = self.get_rows();
 
-            if total == T::zero() {
-                total = T::one();
+            if n != self.get_columns() {
+                return Err(MatrixError::NotSquare {
+                    rows: self.get_rows(),
+                    columns: self.get_columns(),
+                });
             }
-            self.check_size();
 
-            Ok(det / total)
+            LDLT::factorize(self.to_f64().into_elements(), n)
         }
 
-        /// Counts inversed matrix
-        fn inverse(&mut self) {
-            let rows = self.get_rows();
-            let columns = self.get_columns();
-            let mut aug_matrix = self.get_elements();
+        /// Counts QR decomposition with Householder reflections. Works for matrices of any size, computations are made in f64
+        fn qr(&self) -> QR
+        where
+            T: Real,
+        {
+            QR::factorize(self.to_f64(), false)
+        }
 
-            if rows != columns {
-                panic!("Can't inverse this matrix! Maybe rows != columns?");
-            }
+        /// Counts QR decomposition with column pivoting: A * P = Q * R. Reveals numerical rank of matrix
+        fn qr_pivoted(&self) -> QR
+        where
+            T: Real,
+        {
+            QR::factorize(self.to_f64(), true)
+        }
 
-            if self.det() == T::zero() {
-                panic!("Can't inverse this matrix! determinant = 0");
-            }
+        /// Counts QR decomposition of complex matrix with Householder reflections, computations are made in Complex<f64>.
+        /// Q is unitary and diagonal of R may be complex
+        fn complex_qr(&self) -> QR<Complex<f64>>
+        where
+            T: ComplexField,
+        {
+            QR::factorize(self.to_complex(), false)
+        }
 
-            let mut id_matrix = CMatrix::<T>::identity(rows, columns).get_elements();
-            for i in 0..rows {
-                let mut max_row = i;
-                for j in i + 1..rows {
-                    if aug_matrix[j][i] < T::zero() {
-                        aug_matrix[j][i] = aug_matrix[j][i].clone() * (T::zero() - T::one());
-                    }
-                    if aug_matrix[max_row][i] < T::zero() {
-                        aug_matrix[max_row][i] =
-                            aug_matrix[max_row][i].clone() * (T::zero() - T::one());
-                    }
-                    if aug_matrix[j][i] > aug_matrix[max_row][i] {
-                        max_row = j;
-                    }
-                }
-                if max_row != i {
-                    aug_matrix.swap(i, max_row);
-                    id_matrix.swap(i, max_row);
-                }
+        /// Counts QR decomposition of complex matrix with column pivoting: A * P = Q * R
+        fn complex_qr_pivoted(&self) -> QR<Complex<f64>>
+        where
+            T: ComplexField,
+        {
+            QR::factorize(self.to_complex(), true)
+        }
 
-                let pivot = aug_matrix[i][i].clone();
+        /// Counts thin singular value decomposition with one-sided Jacobi rotations, computations are made in f64
+        fn svd(&self) -> SVD
+        where
+            T: Real,
+        {
+            match self.try_svd() {
+                Ok(svd) => svd,
+                Err(e) => panic!("{}", e),
+            }
+        }
 
-                for j in i..rows {
-                    aug_matrix[i][j] = aug_matrix[i][j].clone() / pivot.clone();
-                }
-                for j in 0..rows {
-                    id_matrix[i][j] = id_matrix[i][j].clone() / pivot.clone();
-                }
+        /// Try to count thin singular value decomposition. Returns `NoConvergence` for non-finite elements
+        /// or if Jacobi rotations don't converge
+        fn try_svd(&self) -> Result<SVD, MatrixError>
+        where
+            T: Real,
+        {
+            SVD::factorize(self.to_f64())
+        }
 
-                for j in 0..rows {
-                    if j != i {
-                        let factor = aug_matrix[j][i].clone();
-                        for k in i..rows {
-                            aug_matrix[j][k] = aug_matrix[j][k].clone()
-                                - factor.clone() * aug_matrix[i][k].clone();
-                        }
-                        for k in 0..rows {
-                            id_matrix[j][k] =
-                                id_matrix[j][k].clone() - factor.clone() * id_matrix[i][k].clone();
-                        }
-                    }
-                }
+        /// Counts singular values in descending order without computing singular vectors
+        fn singular_values(&self) -> Vec<f64>
+        where
+            T: Real,
+        {
+            match self.try_singular_values() {
+                Ok(s) => s,
+                Err(e) => panic!("{}", e),
             }
+        }
 
-            self.set_elements(id_matrix);
-            self.check_size();
+        /// Try to count singular values in descending order. Fails like `try_svd`
+        fn try_singular_values(&self) -> Result<Vec<f64>, MatrixError>
+        where
+            T: Real,
+        {
+            SVD::values(self.to_f64())
         }
 
-        /// Try to count inversed matrix
-        fn try_inverse(&mut self) -> Result<(), Error> {
-            let rows = self.get_rows();
-            let columns = self.get_columns();
-            let mut aug_matrix = self.get_elements();
+        /// Counts eigenvalues and orthonormal eigenvectors of symmetric matrix, computations are made in f64
+        fn symmetric_eigen(&self) -> SymmetricEigen
+        where
+            T: Real,
+        {
+            match self.try_symmetric_eigen() {
+                Ok(eigen) => eigen,
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        /// Try to count eigenvalues and orthonormal eigenvectors of symmetric matrix
+        fn try_symmetric_eigen(&self) -> Result<SymmetricEigen, MatrixError>
+        where
+            T: Real,
+        {
+            let n = self.get_rows();
 
-            if rows != columns {
-                return Err(Error(String::from(
-                    "Can't inverse this matrix! Maybe rows != columns?",
-                )));
+            if n != self.get_columns() {
+                return Err(MatrixError::NotSquare {
+                    rows: self.get_rows(),
+                    columns: self.get_columns(),
+                });
             }
 
-            if self.det() == T::zero() {
-                panic!("Can't inverse this matrix! determinant = 0");
+            let a = self.to_f64().into_elements();
+            if !is_symmetric(&a, n) {
+                return Err(MatrixError::NotSymmetric);
             }
 
-            let mut id_matrix = CMatrix::<T>::identity(rows, columns).get_elements();
-            for i in 0..rows {
-                let mut max_row = i;
-                for j in i + 1..rows {
-                    if aug_matrix[j][i] < T::zero() {
-                        aug_matrix[j][i] = aug_matrix[j][i].clone() * (T::zero() - T::one());
-                    }
-                    if aug_matrix[max_row][i] < T::zero() {
-                        aug_matrix[max_row][i] =
-                            aug_matrix[max_row][i].clone() * (T::zero() - T::one());
-                    }
-                    if aug_matrix[j][i] > aug_matrix[max_row][i] {
-                        max_row = j;
-                    }
-                }
-                if max_row != i {
-                    aug_matrix.swap(i, max_row);
-                    id_matrix.swap(i, max_row);
-                }
+            SymmetricEigen::decompose(a, n)
+        }
 
-                let pivot = aug_matrix[i][i].clone();
+        /// Counts real Schur form of matrix, computations are made in f64
+        fn schur(&self) -> RealSchur
+        where
+            T: Real,
+        {
+            match self.try_schur() {
+                Ok(schur) => schur,
+                Err(e) => panic!("{}", e),
+            }
+        }
 
-                for j in i..rows {
-                    aug_matrix[i][j] = aug_matrix[i][j].clone() / pivot.clone();
-                }
-                for j in 0..rows {
-                    id_matrix[i][j] = id_matrix[i][j].clone() / pivot.clone();
-                }
+        /// Try to count real Schur form of matrix
+        fn try_schur(&self) -> Result<RealSchur, MatrixError>
+        where
+            T: Real,
+        {
+            let n = self.get_rows();
 
-                for j in 0..rows {
-                    if j != i {
-                        let factor = aug_matrix[j][i].clone();
-                        for k in i..rows {
-                            aug_matrix[j][k] = aug_matrix[j][k].clone()
-                                - factor.clone() * aug_matrix[i][k].clone();
-                        }
-                        for k in 0..rows {
-                            id_matrix[j][k] =
-                                id_matrix[j][k].clone() - factor.clone() * id_matrix[i][k].clone();
-                        }
-                    }
-                }
+            if n != self.get_columns() {
+                return Err(MatrixError::NotSquare {
+                    rows: self.get_rows(),
+                    columns: self.get_columns(),
+                });
             }
-            self.set_elements(id_matrix);
-            Ok(self.check_size())
+
+            RealSchur::decompose(self.to_f64().into_elements(), n)
         }
 
-        /// Writes matrix to file
-        fn to_file(&self, filename: String, delimiter: char) {
-            use std::fs::OpenOptions;
-            use std::io::Write;
+        /// Counts eigenvalues of matrix, including complex conjugate pairs
+        fn eigenvalues(&self) -> Vec<Complex<f64>>
+        where
+            T: Real,
+        {
+            self.schur().values
+        }
 
-            let rows = self.get_rows();
-            let columns = self.get_columns();
-            let m = self.get_elements();
-
-            let mut file = OpenOptions::new()
-                .write(true)
-                .read(true)
-                .open(filename.clone())
-                .expect(&format!("Can't open file with filename {filename}"));
-
-            for i in 0..rows {
-                for j in 0..columns {
-                    if i == rows - 1 && j == columns - 1 {
-                        write!(file, "{:?}", m[i][j])
-                            .expect(&format!("Can't write to file with filename {filename}"));
-                    } else {
-                        write!(file, "{:?}{delimiter}", m[i][j])
-                            .expect(&format!("Can't write to file with filename {filename}"));
-                    }
-                }
+        /// Try to count eigenvalues of matrix, including complex conjugate pairs
+        fn try_eigenvalues(&self) -> Result<Vec<Complex<f64>>, MatrixError>
+        where
+            T: Real,
+        {
+            Ok(self.try_schur()?.values)
+        }
+
+        /// Counts unit eigenvectors with inverse iteration, in the same order as `eigenvalues()`
+        fn eigenvectors(&self) -> Vec<Vec<Complex<f64>>>
+        where
+            T: Real,
+        {
+            match self.try_eigenvectors() {
+                Ok(vectors) => vectors,
+                Err(e) => panic!("{}", e),
             }
-            self.check_size();
         }
 
-        /// Try to write matrix to file
-        fn try_to_file(&self, filename: String, delimiter: char) -> Result<(), Error> {
-            use std::fs::OpenOptions;
-            use std::io::Write;
+        /// Try to count unit eigenvectors with inverse iteration, in the same order as `eigenvalues()`.
+        /// Repeated eigenvalues get linearly independent vectors. Returns `NoConvergence` for defective matrices,
+        /// which don't have enough eigenvectors
+        fn try_eigenvectors(&self) -> Result<Vec<Vec<Complex<f64>>>, MatrixError>
+        where
+            T: Real,
+        {
+            let values = self.try_eigenvalues()?;
+            let a = self.to_f64();
 
-            let rows = self.get_rows();
-            let columns = self.get_columns();
-            let m = self.get_elements();
+            inverse_iteration(a.as_slice(), a.get_rows(), &values)
+        }
+
+        /// Writes matrix to file. File is created if it doesn't exist and truncated if it does
+        fn to_file<P: AsRef<Path>>(&self, filename: P, delimiter: char)
+        where
+            T: std::fmt::Display,
+        {
+            if let Err(e) = self.try_to_file(filename, delimiter) {
+                panic!("{}", e);
+            }
+        }
 
-            let file = OpenOptions::new()
-                .write(true)
-                .read(true)
-                .open(filename.clone());
+        /// Try to write matrix to file. File is created if it doesn't exist and truncated if it does
+        fn try_to_file<P: AsRef<Path>>(&self, filename: P, delimiter: char) -> Result<(), MatrixError>
+        where
+            T: std::fmt::Display,
+        {
+            self.write_to(BufWriter::new(File::create(filename)?), delimiter)
+        }
 
-            let mut file = match file {
-                Ok(file) => file,
-                Err(_) => return Err(Error(format!("Can't open file with filename '{filename}'"))),
-            };
+        /// Try to write matrix to any writer, e.g. socket or in-memory buffer.
+        /// Writes `# rows columns` header line, then one line per row with elements separated by `delimiter`.
+        /// Elements are written with `Display`, so `read_from` parses them back, e.g. `1+2i` or `1/2`
+        fn write_to<W: Write>(&self, mut writer: W, delimiter: char) -> Result<(), MatrixError>
+        where
+            T: std::fmt::Display,
+        {
+            self.check_size();
+            writeln!(writer, "# {} {}", self.get_rows(), self.get_columns())?;
 
-            for i in 0..rows {
-                for j in 0..columns {
-                    if i == rows - 1 && j == columns - 1 {
-                        let res = write!(file, "{:?}", m[i][j]);
-                        match res {
-                            Ok(_) => (),
-                            Err(_) => {
-                                return Err(Error(format!(
-                                    "Can't write to file with filename '{filename}'"
-                                )))
-                            }
-                        }
+            for i in 0..self.get_rows() {
+                for (j, e) in self.row(i).iter().enumerate() {
+                    if j == 0 {
+                        write!(writer, "{}", e)?;
                     } else {
-                        let res = write!(file, "{:?}{delimiter}", m[i][j]);
-                        match res {
-                            Ok(_) => (),
-                            Err(_) => {
-                                return Err(Error(format!(
-                                    "Can't write to file with filename '{filename}'"
-                                )))
-                            }
-                        }
+                        write!(writer, "{delimiter}{}", e)?;
                     }
                 }
+                writeln!(writer)?;
             }
-            self.check_size();
+            writer.flush()?;
             Ok(())
         }
 
-        /// Counts norm of matrix
+        /// Counts Frobenius norm of matrix
         fn norm(&self) -> f64 {
-            let rows = self.get_rows();
-            let columns = self.get_columns();
-            let elems = self.get_elements();
-            let mut norm = 0.0;
-
-            for i in 0..rows {
-                for j in 0..columns {
-                    norm = norm + elems[i][j].clone().into() * elems[i][j].clone().into();
-                }
-            }
             self.check_size();
-            norm.sqrt()
+            self.as_slice()
+                .iter()
+                .map(|e| e.modulus().powi(2))
+                .sum::<f64>()
+                .sqrt()
         }
 
-        /// Converts matrix' values to f64
-        fn to_f64(&mut self) -> CMatrix<f64> {
+        /// Converts matrix' values to f64. Values which can't be represented become NaN
+        fn to_f64(&self) -> CMatrix<f64>
+        where
+            T: Real,
+        {
             self.check_size();
-            let rows = self.get_rows();
-            let columns = self.get_columns();
-            let elems = self.get_elements();
-            let mut f64_elems = vec![];
 
-            for i in 0..rows {
-                let mut c = vec![];
-                for j in 0..columns {
-                    c.push(elems[i][j].clone().into())
-                }
-                f64_elems.push(c);
+            CMatrix {
+                rows: self.get_rows(),
+                columns: self.get_columns(),
+                elems: self.as_slice().iter().map(|e| e.to_f64().unwrap_or(f64::NAN)).collect(),
             }
+        }
+
+        /// Converts matrix' values to Complex<f64>
+        fn to_complex(&self) -> CMatrix<Complex<f64>>
+        where
+            T: ComplexField,
+        {
+            self.check_size();
 
-            let mut r = CMatrix::from_element(rows, columns, 0.0);
-            r.set_elements(f64_elems);
-            r
+            CMatrix {
+                rows: self.get_rows(),
+                columns: self.get_columns(),
+                elems: self.as_slice().iter().map(|e| e.to_complex()).collect(),
+            }
         }
-        
-        fn try_remove_row(&self, index: usize) -> Result<CMatrix<T>, Error> {
+
+        fn try_remove_row(&self, index: usize) -> Result<CMatrix<T>, MatrixError> {
             let mut elems = self.get_elements();
             let rows = self.get_rows();
 
             if rows <= index {
-                return Err(Error(String::from("Wrong index value!")));
+                return Err(MatrixError::IndexOutOfBounds { index, bound: rows });
             }
 
             elems.remove(index);
@@ -487,196 +1159,257 @@ pub mod matrix {
             Ok(c)
         }
 
-        fn try_remove_column(&self, index: usize) -> Result<CMatrix<T>, Error> {
+        fn try_remove_column(&self, index: usize) -> Result<CMatrix<T>, MatrixError> {
             let mut elems = self.get_elements();
             let columns = self.get_columns();
 
             if columns <= index {
-                return Err(Error(String::from("Wrong index value!")));
+                return Err(MatrixError::IndexOutOfBounds {
+                    index,
+                    bound: columns,
+                });
             }
 
-            for i in 0..self.get_rows() {
-                elems[i].remove(index);
+            for row in elems.iter_mut() {
+                row.remove(index);
             }
             let mut c = CMatrix::zero(self.get_rows(), columns - 1);
             c.set_elements(elems);
             Ok(c)
         }
 
-        fn try_insert_row(&self, index: usize, row: Vec<T>) -> Result<CMatrix<T>, Error> {
+        fn try_insert_row(&self, index: usize, row: Vec<T>) -> Result<CMatrix<T>, MatrixError> {
             let mut elems = self.get_elements();
             let columns = self.get_columns();
             let rows = self.get_rows();
 
+            if rows < index {
+                return Err(MatrixError::IndexOutOfBounds {
+                    index,
+                    bound: rows + 1,
+                });
+            }
             if row.len() != columns {
-                return Err(Error(String::from("Wrong row size!")));
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (1, columns),
+                    found: (1, row.len()),
+                });
             }
 
             elems.insert(index, row);
-            let mut c = CMatrix::zero(rows + 1, columns);
-            c.set_elements(elems);
-            Ok(c)
+            let (_, _, elems) = flatten_rows(elems);
+            Ok(CMatrix {
+                rows: rows + 1,
+                columns,
+                elems,
+            })
         }
 
-        fn try_insert_column(&self, index: usize, column: Vec<T>) -> Result<CMatrix<T>, Error> {
+        fn try_insert_column(&self, index: usize, column: Vec<T>) -> Result<CMatrix<T>, MatrixError> {
             let mut elems = self.get_elements();
             let columns = self.get_columns();
             let rows = self.get_rows();
 
-            if column.len() != columns {
-                return Err(Error(String::from("Wrong column size!")));
+            if columns < index {
+                return Err(MatrixError::IndexOutOfBounds {
+                    index,
+                    bound: columns + 1,
+                });
+            }
+            if column.len() != rows {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (rows, 1),
+                    found: (column.len(), 1),
+                });
             }
 
-            for i in 0..columns {
-                elems[i].insert(index, column[i]);
+            for (row, e) in elems.iter_mut().zip(column) {
+                row.insert(index, e);
             }
-            let mut c = CMatrix::zero(rows + 1, columns);
-            c.set_elements(elems);
-            Ok(c)
+            let (_, _, elems) = flatten_rows(elems);
+            Ok(CMatrix {
+                rows,
+                columns: columns + 1,
+                elems,
+            })
         }
 
-        fn try_get_rows(&self, index: usize, amount: usize) -> Result<CMatrix<T>, Error> {
-            let mut elems = self.get_elements();
-            let columns = self.get_columns();
+        /// Returns `amount` rows starting from `index`. Zero amount gives matrix with no rows
+        fn try_get_rows(&self, index: usize, amount: usize) -> Result<CMatrix<T>, MatrixError> {
             let rows = self.get_rows();
+            let columns = self.get_columns();
 
-            if amount == 0 {
-                let mut c = CMatrix::zero(rows + 1, columns);
-                c.set_elements(elems);
-                return Ok(c);
-            }
-
-            if rows <= index {
-                return Err(Error(String::from("Wrong index value!")));
+            if rows < index || (rows == index && amount != 0) {
+                return Err(MatrixError::IndexOutOfBounds { index, bound: rows });
             }
 
-            if rows < index + amount {
-                return Err(Error(String::from(
-                    "Can't take that amount of rows from that index!",
-                )));
+            if rows - index < amount {
+                return Err(MatrixError::IndexOutOfBounds {
+                    index: index.saturating_add(amount - 1),
+                    bound: rows,
+                });
             }
 
-            for i in 0..index {
-                elems.remove(i);
-            }
-            for i in index + amount..rows {
-                elems.remove(i);
-            }
-            let mut c = CMatrix::zero(rows + 1, columns);
-            c.set_elements(elems);
-            Ok(c)
+            Ok(CMatrix {
+                rows: amount,
+                columns,
+                elems: self.as_slice()[index * columns..(index + amount) * columns].to_vec(),
+            })
         }
 
-        fn try_get_columns(&self, index: usize, amount: usize) -> Result<CMatrix<T>, Error> {
-            let mut elems = self.get_elements();
-            let columns = self.get_columns();
+        /// Returns `amount` columns starting from `index`. Zero amount gives matrix with no columns
+        fn try_get_columns(&self, index: usize, amount: usize) -> Result<CMatrix<T>, MatrixError> {
             let rows = self.get_rows();
+            let columns = self.get_columns();
 
-            if amount == 0 {
-                let mut c = CMatrix::zero(rows + 1, columns);
-                c.set_elements(elems);
-                return Ok(c);
+            if columns < index || (columns == index && amount != 0) {
+                return Err(MatrixError::IndexOutOfBounds {
+                    index,
+                    bound: columns,
+                });
             }
 
-            if rows <= index {
-                return Err(Error(String::from("Wrong index value!")));
+            if columns - index < amount {
+                return Err(MatrixError::IndexOutOfBounds {
+                    index: index.saturating_add(amount - 1),
+                    bound: columns,
+                });
             }
 
-            if rows < index + amount {
-                return Err(Error(String::from(
-                    "Can't take that amount of columns from that index!",
-                )));
-            }
-            for j in 0..columns {
-                for i in 0..index {
-                    elems[j].remove(i);
-                }
-            }
-            for j in 0..columns {
-                for i in index + amount..columns {
-                    elems[j].remove(i);
-                }
-            }
-            let mut c = CMatrix::zero(rows + 1, columns);
-            c.set_elements(elems);
-            Ok(c)
+            Ok(CMatrix {
+                rows,
+                columns: amount,
+                elems: (0..rows).flat_map(|i| self.row(i)[index..index + amount].iter().cloned()).collect(),
+            })
         }
 
-        fn try_replace_row(&self, index: usize, row: Vec<T>) -> Result<CMatrix<T>, Error> {
+        fn try_replace_row(&self, index: usize, row: Vec<T>) -> Result<CMatrix<T>, MatrixError> {
             let mut elems = self.get_elements();
             let columns = self.get_columns();
             let rows = self.get_rows();
 
             if rows <= index {
-                return Err(Error(String::from("Wrong index value!")));
+                return Err(MatrixError::IndexOutOfBounds { index, bound: rows });
             }
             if row.len() != columns {
-                return Err(Error(String::from("Wrong row size!")));
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (1, columns),
+                    found: (1, row.len()),
+                });
             }
 
-            elems.remove(index);
-            elems.insert(index, row);
-            let mut c = CMatrix::zero(rows + 1, columns);
-            c.set_elements(elems);
-            Ok(c)
+            elems[index] = row;
+            let (_, _, elems) = flatten_rows(elems);
+            Ok(CMatrix { rows, columns, elems })
         }
 
-        fn try_replace_column(&self, index: usize, column: Vec<T>) -> Result<CMatrix<T>, Error> {
+        fn try_replace_column(&self, index: usize, column: Vec<T>) -> Result<CMatrix<T>, MatrixError> {
             let mut elems = self.get_elements();
             let columns = self.get_columns();
             let rows = self.get_rows();
 
             if columns <= index {
-                return Err(Error(String::from("Wrong index value!")));
+                return Err(MatrixError::IndexOutOfBounds {
+                    index,
+                    bound: columns,
+                });
             }
-            if column.len() != columns {
-                return Err(Error(String::from("Wrong row size!")));
+            if column.len() != rows {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (rows, 1),
+                    found: (column.len(), 1),
+                });
             }
-            for i in 0..rows {
-                elems[i].remove(index);
-                elems[i].insert(index, column[i]);
+            for (row, e) in elems.iter_mut().zip(column) {
+                row[index] = e;
             }
 
-            let mut c = CMatrix::zero(rows + 1, columns);
-            c.set_elements(elems);
-            Ok(c)
+            let (_, _, elems) = flatten_rows(elems);
+            Ok(CMatrix { rows, columns, elems })
         }
         /// Checks if this elem contains to matrix
         fn contains(&self, element: T) -> bool {
-            let elems = self.get_elements();
+            self.as_slice().contains(&element)
+        }
+        /// Search element's position in matrix as (row, column). Return (-1, -1) if there is no this element in matrix
+        fn find(&self, element: T) -> (i32, i32) {
+            let columns = self.get_columns();
 
-            for i in elems {
-                if i.contains(&element) {
-                    return true;
-                }
+            match self.as_slice().iter().position(|e| *e == element) {
+                Some(i) => ((i / columns) as i32, (i % columns) as i32),
+                None => (-1, -1),
             }
-            false
         }
-        /// Search elemnt's position in matrix. Return (-1, -1) if there is no this element in matrix
-        fn find(&self, element: T) -> (i32, i32) {
-            let elems = self.get_elements();
-            let mut c = 0;
-            let mut r = 0;
-
-            for i in elems {
-                c += 1;
-                if i.contains(&element) {
-                    r = i.iter().position(|&r| r == element).unwrap() as i32;
-                    break;
-                }
-                r = -1;
+        /// Returns reference to element (row, column), or None if it is out of bounds
+        fn get(&self, row: usize, column: usize) -> Option<&T> {
+            let columns = self.get_columns();
+
+            if row < self.get_rows() && column < columns {
+                self.as_slice().get(row * columns + column)
+            } else {
+                None
             }
+        }
+        /// Returns mutable reference to element (row, column), or None if it is out of bounds
+        fn get_mut(&mut self, row: usize, column: usize) -> Option<&mut T> {
+            let columns = self.get_columns();
 
+            if row < self.get_rows() && column < columns {
+                self.as_mut_slice().get_mut(row * columns + column)
+            } else {
+                None
+            }
+        }
+        /// Returns reference to element (row, column). Unlike indexing, returns an error instead of panic
+        fn checked_index(&self, row: usize, column: usize) -> Result<&T, MatrixError> {
+            let (rows, columns) = (self.get_rows(), self.get_columns());
+
+            match self.get(row, column) {
+                Some(e) => Ok(e),
+                None if row >= rows => Err(MatrixError::IndexOutOfBounds { index: row, bound: rows }),
+                None => Err(MatrixError::IndexOutOfBounds {
+                    index: column,
+                    bound: columns,
+                }),
+            }
+        }
+        /// Returns mutable reference to element (row, column). Unlike indexing, returns an error instead of panic
+        fn checked_index_mut(&mut self, row: usize, column: usize) -> Result<&mut T, MatrixError> {
+            let (rows, columns) = (self.get_rows(), self.get_columns());
 
-            (r, c)
+            if row >= rows {
+                return Err(MatrixError::IndexOutOfBounds { index: row, bound: rows });
+            }
+            if column >= columns {
+                return Err(MatrixError::IndexOutOfBounds {
+                    index: column,
+                    bound: columns,
+                });
+            }
+            Ok(&mut self.as_mut_slice()[row * columns + column])
+        }
+        /// Returns row of matrix as slice
+        fn row(&self, index: usize) -> &[T] {
+            let columns = self.get_columns();
+            &self.as_slice()[index * columns..(index + 1) * columns]
         }
         /// Returns rows amount
         fn get_rows(&self) -> usize;
         /// Returns columns amount
         fn get_columns(&self) -> usize;
-        /// Returns elements of matrix as Vec<Vec>
-        fn get_elements(&self) -> Vec<Vec<T>>;
+        /// Returns a copy of matrix elements as Vec<Vec>. Prefer `get`, `row` or `as_slice` to read elements without copying
+        fn get_elements(&self) -> Vec<Vec<T>> {
+            (0..self.get_rows()).map(|i| self.row(i).to_vec()).collect()
+        }
         /// Set elements to matrix
         fn set_elements(&mut self, v: Vec<Vec<T>>);
+        /// Returns elements of matrix as a row-major slice
+        fn as_slice(&self) -> &[T];
+        /// Returns elements of matrix as a mutable row-major slice
+        fn as_mut_slice(&mut self) -> &mut [T];
+        /// Consumes matrix and returns its elements as a row-major vector
+        fn into_elements(self) -> Vec<T>
+        where
+            Self: Sized;
     }
 }
@@ -1,10 +1,11 @@
 pub mod matrix23 {
-    extern crate num;
+    use crate::{matrix::matrix::MatrixError, CMatrix};
+    use crate::Scalar;
+    use std::fs::File;
+    use std::io::Read;
+    use std::path::Path;
 
-    use crate::{matrix::matrix::Error, CMatrix};
-    use self::num::Num;
-
-    pub trait Matrix23<T: Num + Default + Clone + Copy + PartialOrd + std::str::FromStr + std::fmt::Debug + std::convert::Into<f64>> {
+    pub trait Matrix23<T: Scalar> {
         /// Creates a matrix with zero as its elements
         fn zero() -> Self;
         /// Creates a matrix with one as its elements
@@ -12,11 +13,21 @@ pub mod matrix23 {
         /// Creates an identity matrix
         fn identity() -> Self;
         /// Read matrix from file
-        fn from_file(filename: String, delimiter: char) -> Self
-        where <T as std::str::FromStr>::Err: std::fmt::Debug;
+        fn from_file<P: AsRef<Path>>(filename: P, delimiter: char) -> Self
+        where <T as std::str::FromStr>::Err: std::fmt::Debug, Self : Sized {
+            match Self::try_from_file(filename, delimiter) {
+                Ok(m) => m,
+                Err(e) => panic!("{}", e),
+            }
+        }
         /// Try to read matrix elements from file
-        fn try_from_file(filename: String, delimiter: char) -> Result<Self, Error>
-        where <T as std::str::FromStr>::Err: std::fmt::Debug, Self : Sized;
+        fn try_from_file<P: AsRef<Path>>(filename: P, delimiter: char) -> Result<Self, MatrixError>
+        where <T as std::str::FromStr>::Err: std::fmt::Debug, Self : Sized {
+            Self::read_from(File::open(filename)?, delimiter)
+        }
+        /// Try to read matrix elements from any reader, e.g. socket or in-memory buffer
+        fn read_from<R: Read>(reader: R, delimiter: char) -> Result<Self, MatrixError>
+        where Self : Sized;
         /// Creates a matrix from element
         fn from_element(e: T) -> Self;
         /// Creates a matrix from vector, using that elements as columns
@@ -0,0 +1,87 @@
+pub mod matrix4 {
+    use crate::Scalar;
+    pub use crate::cmatrix::cmatrix::CMatrix;
+    pub use crate::matrix::matrix::Matrix;
+    pub use crate::matrix23_trait::matrix23::Matrix23;
+    use crate::smatrix::smatrix::SMatrix;
+
+    /// Matrix 4x4
+    pub type Matrix4<T> = SMatrix<T, 4, 4>;
+
+    impl<T: Scalar> Matrix4<T> {
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(
+            m11: T,
+            m12: T,
+            m13: T,
+            m14: T,
+            m21: T,
+            m22: T,
+            m23: T,
+            m24: T,
+            m31: T,
+            m32: T,
+            m33: T,
+            m34: T,
+            m41: T,
+            m42: T,
+            m43: T,
+            m44: T,
+        ) -> Matrix4<T> {
+            SMatrix::from_array([
+                [m11, m12, m13, m14],
+                [m21, m22, m23, m24],
+                [m31, m32, m33, m34],
+                [m41, m42, m43, m44],
+            ])
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{mat4, CMatrix, CMatrixTrait, Matrix, Matrix23, Matrix4};
+
+    #[test]
+    fn matrix4_i32_add_test() {
+        let m = Matrix4::<i32>::one();
+        let m2 = Matrix4::<i32>::one();
+
+        assert_eq!((m + m2)[(3, 3)], 2);
+    }
+
+    #[test]
+    fn matrix4_f32_sub_test() {
+        let m = Matrix4::<f32>::one();
+        let m2 = Matrix4::<f32>::identity();
+
+        assert_eq!((m.clone() - m2.clone())[(0, 0)], 0.0);
+        assert_eq!((m - m2)[(0, 1)], 1.0);
+    }
+
+    #[test]
+    fn matrix4_mul_test() {
+        let m = mat4![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+        let id = Matrix4::identity();
+
+        assert_eq!(m.clone() * id, m);
+        assert_eq!((m.clone() * m.clone())[(0, 0)], 90);
+        assert_eq!((m.clone() * m)[(3, 3)], 600);
+    }
+
+    #[test]
+    fn matrix4_det_test() {
+        let m = Matrix4::new(2, 0, 1, 6, 3, 2, 8, 4, 4, 4, 4, 4, 8, 7, 9, 5);
+
+        assert_eq!(m.det(), 208);
+    }
+
+    #[test]
+    fn matrix4_cmatrix_convert_test() {
+        let m = Matrix4::from_diagonal(3);
+        let c = m.clone().to_cmatrix();
+
+        assert_eq!(c, CMatrix::from_diagonal(4, 4, 3));
+        assert_eq!(c.to_matrix4(), m);
+    }
+}
@@ -0,0 +1,512 @@
+pub mod matrix_market {
+    use crate::{Real, Scalar};
+    use crate::matrix::matrix::split_tokens;
+    use crate::{CMatrix, MatrixError};
+    use std::collections::HashSet;
+    use std::io::{Read, Write};
+
+    /// Storage format of Matrix Market file
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum MatrixMarketFormat {
+        /// Nonzero entries with their indices
+        Coordinate,
+        /// All entries in column-major order
+        Array,
+    }
+
+    /// Symmetry qualifier of Matrix Market file. Only the lower triangle of symmetric matrices is stored
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum MatrixMarketSymmetry {
+        General,
+        Symmetric,
+        /// A^T = -A, the diagonal is zero and isn't stored
+        SkewSymmetric,
+    }
+
+    impl MatrixMarketFormat {
+        fn name(&self) -> &'static str {
+            match self {
+                MatrixMarketFormat::Coordinate => "coordinate",
+                MatrixMarketFormat::Array => "array",
+            }
+        }
+    }
+
+    impl MatrixMarketSymmetry {
+        fn name(&self) -> &'static str {
+            match self {
+                MatrixMarketSymmetry::General => "general",
+                MatrixMarketSymmetry::Symmetric => "symmetric",
+                MatrixMarketSymmetry::SkewSymmetric => "skew-symmetric",
+            }
+        }
+    }
+
+    impl<T: Scalar> CMatrix<T> {
+        /// Reads matrix in Matrix Market format. Supports `real`, `integer` and `pattern` fields,
+        /// pattern entries are read as ones. Symmetric and skew-symmetric matrices are expanded to full storage
+        pub fn from_matrix_market<R: Read>(mut reader: R) -> Result<CMatrix<T>, MatrixError> {
+            let mut s = String::new();
+            reader.read_to_string(&mut s)?;
+
+            let (format, pattern, symmetry) = match s.lines().next() {
+                Some(line) => parse_banner(line)?,
+                None => return Err(format_error(1, "missing header")),
+            };
+
+            let mut lines = s
+                .lines()
+                .enumerate()
+                .skip(1)
+                .map(|(i, line)| (i + 1, line))
+                .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('%'));
+            let last_line = s.lines().count();
+
+            let (line, size) = match lines.next() {
+                Some(size) => size,
+                None => return Err(format_error(last_line, "missing size line")),
+            };
+            let size_len = match format {
+                MatrixMarketFormat::Coordinate => 3,
+                MatrixMarketFormat::Array => 2,
+            };
+            let size = split_tokens(size, ' ');
+            if size.len() != size_len {
+                return Err(format_error(
+                    line,
+                    &format!("expected {} sizes, found {}", size_len, size.len()),
+                ));
+            }
+            let size = size
+                .iter()
+                .map(|&(column, token)| parse_token::<usize>(line, column, token))
+                .collect::<Result<Vec<usize>, MatrixError>>()?;
+            let (rows, columns) = (size[0], size[1]);
+
+            if symmetry != MatrixMarketSymmetry::General && rows != columns {
+                return Err(format_error(line, "symmetric matrix must be square"));
+            }
+
+            let len = match rows.checked_mul(columns) {
+                Some(len) if len.saturating_mul(std::mem::size_of::<T>()) <= isize::MAX as usize => len,
+                _ => return Err(format_error(line, "matrix is too large")),
+            };
+            let entries = match format {
+                MatrixMarketFormat::Coordinate => size[2],
+                MatrixMarketFormat::Array => match symmetry {
+                    MatrixMarketSymmetry::General => len,
+                    MatrixMarketSymmetry::Symmetric => (len - rows) / 2 + rows,
+                    MatrixMarketSymmetry::SkewSymmetric => (len - rows) / 2,
+                },
+            };
+            // every stored entry takes at least one byte of input
+            if entries > s.len() {
+                return Err(format_error(
+                    line,
+                    &format!("expected {} entries, file is too short", entries),
+                ));
+            }
+
+            let mut elems = Vec::new();
+            if elems.try_reserve_exact(len).is_err() {
+                return Err(format_error(line, "matrix is too large"));
+            }
+            elems.resize(len, T::zero());
+            let mut m = CMatrix { rows, columns, elems };
+
+            match format {
+                MatrixMarketFormat::Coordinate => {
+                    let width = if pattern { 2 } else { 3 };
+                    let mut seen = HashSet::new();
+
+                    for (line, entry) in lines {
+                        let tokens = split_tokens(entry, ' ');
+                        if tokens.len() != width {
+                            return Err(format_error(
+                                line,
+                                &format!("expected {} values, found {}", width, tokens.len()),
+                            ));
+                        }
+
+                        let i = parse_index(line, tokens[0], rows)?;
+                        let j = parse_index(line, tokens[1], columns)?;
+                        let value = if pattern {
+                            T::one()
+                        } else {
+                            parse_token(line, tokens[2].0, tokens[2].1)?
+                        };
+
+                        if symmetry == MatrixMarketSymmetry::SkewSymmetric && i == j && !value.is_zero() {
+                            return Err(format_error(line, "skew-symmetric matrix must have zero diagonal"));
+                        }
+                        // mirrored entries of symmetric matrices occupy the same position
+                        let position = match symmetry {
+                            MatrixMarketSymmetry::General => (i, j),
+                            _ => (i.max(j), i.min(j)),
+                        };
+                        if !seen.insert(position) {
+                            return Err(format_error(
+                                line,
+                                &format!("duplicate entry ({}, {})", i + 1, j + 1),
+                            ));
+                        }
+
+                        set(&mut m, line, i, j, value, symmetry)?;
+                    }
+
+                    if seen.len() != size[2] {
+                        return Err(format_error(
+                            last_line,
+                            &format!("expected {} entries, found {}", size[2], seen.len()),
+                        ));
+                    }
+                }
+                MatrixMarketFormat::Array => {
+                    let positions = stored_positions(rows, columns, symmetry);
+                    let mut count = 0;
+
+                    for (line, entry) in lines {
+                        for (column, token) in split_tokens(entry, ' ') {
+                            let (i, j) = match positions.get(count) {
+                                Some(&position) => position,
+                                None => {
+                                    return Err(format_error(
+                                        line,
+                                        &format!("expected {} entries, found more", positions.len()),
+                                    ))
+                                }
+                            };
+
+                            set(&mut m, line, i, j, parse_token(line, column, token)?, symmetry)?;
+                            count += 1;
+                        }
+                    }
+
+                    if count != positions.len() {
+                        return Err(format_error(
+                            last_line,
+                            &format!("expected {} entries, found {}", positions.len(), count),
+                        ));
+                    }
+                }
+            }
+
+            Ok(m)
+        }
+
+        /// Writes matrix in Matrix Market format with `real` field. Coordinate format stores only nonzero entries.
+        /// Returns error if matrix doesn't have requested symmetry
+        pub fn to_matrix_market<W: Write>(
+            &self,
+            mut writer: W,
+            format: MatrixMarketFormat,
+            symmetry: MatrixMarketSymmetry,
+        ) -> Result<(), MatrixError>
+        where
+            T: Real,
+        {
+            if symmetry != MatrixMarketSymmetry::General {
+                if self.rows != self.columns {
+                    return Err(MatrixError::NotSquare {
+                        rows: self.rows,
+                        columns: self.columns,
+                    });
+                }
+
+                for i in 0..self.rows {
+                    for j in 0..=i {
+                        let (a, b) = (self.entry(i, j), self.entry(j, i));
+                        let mirrored = match symmetry {
+                            MatrixMarketSymmetry::SkewSymmetric => b.checked_neg().as_ref() == Some(a),
+                            _ => a == b,
+                        };
+                        if !mirrored {
+                            return Err(MatrixError::NotSymmetric);
+                        }
+                    }
+                }
+            }
+
+            writeln!(
+                writer,
+                "%%MatrixMarket matrix {} real {}",
+                format.name(),
+                symmetry.name()
+            )?;
+
+            let positions = stored_positions(self.rows, self.columns, symmetry);
+            match format {
+                MatrixMarketFormat::Coordinate => {
+                    let entries: Vec<(usize, usize)> = positions
+                        .into_iter()
+                        .filter(|&(i, j)| !self.entry(i, j).is_zero())
+                        .collect();
+
+                    writeln!(writer, "{} {} {}", self.rows, self.columns, entries.len())?;
+                    for (i, j) in entries {
+                        writeln!(writer, "{} {} {:?}", i + 1, j + 1, self.entry(i, j))?;
+                    }
+                }
+                MatrixMarketFormat::Array => {
+                    writeln!(writer, "{} {}", self.rows, self.columns)?;
+                    for (i, j) in positions {
+                        writeln!(writer, "{:?}", self.entry(i, j))?;
+                    }
+                }
+            }
+            writer.flush()?;
+            Ok(())
+        }
+
+        fn entry(&self, i: usize, j: usize) -> &T {
+            &self.elems[i * self.columns + j]
+        }
+    }
+
+    /// Parses `%%MatrixMarket matrix <format> <field> <symmetry>` line. Returns format, whether field is pattern and symmetry
+    fn parse_banner(line: &str) -> Result<(MatrixMarketFormat, bool, MatrixMarketSymmetry), MatrixError> {
+        let tokens: Vec<String> = line.split_whitespace().map(|t| t.to_lowercase()).collect();
+
+        if tokens.len() != 5 || tokens[0] != "%%matrixmarket" || tokens[1] != "matrix" {
+            return Err(format_error(
+                1,
+                "expected '%%MatrixMarket matrix <format> <field> <symmetry>'",
+            ));
+        }
+
+        let format = match tokens[2].as_str() {
+            "coordinate" => MatrixMarketFormat::Coordinate,
+            "array" => MatrixMarketFormat::Array,
+            other => return Err(format_error(1, &format!("unknown format '{}'", other))),
+        };
+        let pattern = match tokens[3].as_str() {
+            "real" | "double" | "integer" => false,
+            "pattern" if format == MatrixMarketFormat::Coordinate => true,
+            other => return Err(format_error(1, &format!("unsupported field '{}'", other))),
+        };
+        // Hermitian matrix with real entries is symmetric
+        let symmetry = match tokens[4].as_str() {
+            "general" => MatrixMarketSymmetry::General,
+            "symmetric" | "hermitian" => MatrixMarketSymmetry::Symmetric,
+            "skew-symmetric" => MatrixMarketSymmetry::SkewSymmetric,
+            other => return Err(format_error(1, &format!("unknown symmetry '{}'", other))),
+        };
+
+        Ok((format, pattern, symmetry))
+    }
+
+    /// Returns positions of stored entries in column-major order: all of them or the lower triangle
+    fn stored_positions(rows: usize, columns: usize, symmetry: MatrixMarketSymmetry) -> Vec<(usize, usize)> {
+        let mut positions = Vec::new();
+
+        for j in 0..columns {
+            let start = match symmetry {
+                MatrixMarketSymmetry::General => 0,
+                MatrixMarketSymmetry::Symmetric => j,
+                MatrixMarketSymmetry::SkewSymmetric => j + 1,
+            };
+            positions.extend((start..rows).map(|i| (i, j)));
+        }
+        positions
+    }
+
+    /// Sets entry and its mirror for symmetric matrices. Returns error if mirror of skew-symmetric entry
+    /// can't be represented, e.g. for unsigned element types
+    fn set<T: Scalar>(
+        m: &mut CMatrix<T>,
+        line: usize,
+        i: usize,
+        j: usize,
+        value: T,
+        symmetry: MatrixMarketSymmetry,
+    ) -> Result<(), MatrixError> {
+        let columns = m.columns;
+        match symmetry {
+            MatrixMarketSymmetry::Symmetric => m.elems[j * columns + i] = value.clone(),
+            MatrixMarketSymmetry::SkewSymmetric if i != j => match value.checked_neg() {
+                Some(mirror) => m.elems[j * columns + i] = mirror,
+                None => return Err(format_error(line, &format!("{:?} can't be negated", value))),
+            },
+            _ => (),
+        }
+        m.elems[i * columns + j] = value;
+        Ok(())
+    }
+
+    /// Parses 1-based index and converts it to 0-based
+    fn parse_index(line: usize, (column, token): (usize, &str), bound: usize) -> Result<usize, MatrixError> {
+        let index: usize = parse_token(line, column, token)?;
+
+        if index == 0 || index > bound {
+            return Err(MatrixError::IndexOutOfBounds { index, bound });
+        }
+        Ok(index - 1)
+    }
+
+    fn parse_token<T: std::str::FromStr>(line: usize, column: usize, token: &str) -> Result<T, MatrixError> {
+        token.parse().map_err(|_| MatrixError::Parse {
+            line,
+            column: column + 1,
+            token: token.to_string(),
+        })
+    }
+
+    fn format_error(line: usize, message: &str) -> MatrixError {
+        MatrixError::Format {
+            line,
+            message: message.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cmat, CMatrix, CMatrixTrait, Matrix, MatrixError, MatrixMarketFormat, MatrixMarketSymmetry};
+
+    #[test]
+    fn matrix_market_coordinate_test() {
+        let mtx = "%%MatrixMarket matrix coordinate real general\n\
+                   % comment\n\
+                   2 3 3\n\
+                   1 1 1.5\n\
+                   2 3 -2\n\
+                   1 2 4e1\n";
+        let m = CMatrix::<f64>::from_matrix_market(mtx.as_bytes()).unwrap();
+
+        assert_eq!(m, cmat![1.5, 40.0, 0.0; 0.0, 0.0, -2.0]);
+
+        let pattern = "%%MatrixMarket matrix coordinate pattern general\n2 2 2\n1 2\n2 1\n";
+        assert_eq!(CMatrix::<i32>::from_matrix_market(pattern.as_bytes()).unwrap(), cmat![0, 1; 1, 0]);
+    }
+
+    #[test]
+    fn matrix_market_symmetric_test() {
+        let symmetric = "%%MatrixMarket matrix coordinate integer symmetric\n3 3 3\n1 1 4\n3 1 2\n3 2 -1\n";
+        let skew = "%%MatrixMarket matrix array real skew-symmetric\n3 3\n1\n2\n3\n";
+
+        assert_eq!(
+            CMatrix::<i32>::from_matrix_market(symmetric.as_bytes()).unwrap(),
+            cmat![4, 0, 2; 0, 0, -1; 2, -1, 0]
+        );
+        assert_eq!(
+            CMatrix::<f64>::from_matrix_market(skew.as_bytes()).unwrap(),
+            cmat![0.0, -1.0, -2.0; 1.0, 0.0, -3.0; 2.0, 3.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn matrix_market_array_test() {
+        let mtx = "%%MatrixMarket matrix array real general\n2 3\n1\n4\n2\n5\n3\n6\n";
+        let m = CMatrix::<f64>::from_matrix_market(mtx.as_bytes()).unwrap();
+
+        assert_eq!(m, cmat![1.0, 2.0, 3.0; 4.0, 5.0, 6.0]);
+        assert!(matches!(
+            CMatrix::<f64>::from_matrix_market("%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n".as_bytes()),
+            Err(MatrixError::Format { .. })
+        ));
+    }
+
+    #[test]
+    fn matrix_market_malformed_test() {
+        let headers = [
+            "",
+            "%MatrixMarket matrix coordinate real general\n1 1 0\n",
+            "%%MatrixMarket vector coordinate real general\n1 1 0\n",
+            "%%MatrixMarket matrix sparse real general\n1 1 0\n",
+            "%%MatrixMarket matrix coordinate complex general\n1 1 0\n",
+            "%%MatrixMarket matrix array pattern general\n1 1\n",
+            "%%MatrixMarket matrix coordinate real\n1 1 0\n",
+            "%%MatrixMarket matrix coordinate real general\n1 1\n",
+            "%%MatrixMarket matrix coordinate real symmetric\n1 2 0\n",
+            "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n",
+        ];
+
+        for mtx in headers.iter() {
+            assert!(matches!(
+                CMatrix::<f64>::from_matrix_market(mtx.as_bytes()),
+                Err(MatrixError::Format { .. })
+            ));
+        }
+        assert!(matches!(
+            CMatrix::<f64>::from_matrix_market("%%MatrixMarket matrix coordinate real general\n2 2 1\n3 1 1\n".as_bytes()),
+            Err(MatrixError::IndexOutOfBounds { index: 3, bound: 2 })
+        ));
+        assert!(matches!(
+            CMatrix::<f64>::from_matrix_market("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1 x\n".as_bytes()),
+            Err(MatrixError::Parse { line: 3, column: 5, .. })
+        ));
+    }
+
+    #[test]
+    fn matrix_market_invalid_entries_test() {
+        let files = [
+            "%%MatrixMarket matrix coordinate real general\n18446744073709551615 2 0\n",
+            "%%MatrixMarket matrix coordinate real general\n4294967296 4294967296 0\n",
+            "%%MatrixMarket matrix array real general\n100000 100000\n1\n",
+            "%%MatrixMarket matrix coordinate real general\n2 2 1000000\n1 1 1\n",
+            "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 2 1\n1 2 3\n",
+            "%%MatrixMarket matrix coordinate real symmetric\n2 2 2\n2 1 1\n1 2 3\n",
+            "%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n2 2 1\n",
+            "%%MatrixMarket matrix coordinate pattern skew-symmetric\n2 2 1\n1 1\n",
+        ];
+
+        for mtx in files.iter() {
+            assert!(matches!(
+                CMatrix::<f64>::from_matrix_market(mtx.as_bytes()),
+                Err(MatrixError::Format { .. })
+            ));
+        }
+
+        let zero_diagonal = "%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 2\n1 1 0\n2 1 5\n";
+        assert_eq!(
+            CMatrix::<f64>::from_matrix_market(zero_diagonal.as_bytes()).unwrap(),
+            cmat![0.0, -5.0; 5.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn matrix_market_unsigned_test() {
+        let skew = "%%MatrixMarket matrix coordinate integer skew-symmetric\n2 2 1\n2 1 5\n";
+        let general = "%%MatrixMarket matrix coordinate integer general\n2 2 1\n2 1 5\n";
+
+        assert!(matches!(
+            CMatrix::<u32>::from_matrix_market(skew.as_bytes()),
+            Err(MatrixError::Format { line: 3, .. })
+        ));
+        assert_eq!(CMatrix::<u32>::from_matrix_market(general.as_bytes()).unwrap(), cmat![0, 0; 5, 0]);
+        assert!(matches!(
+            cmat![0u32, 0; 5, 0].to_matrix_market(Vec::new(), MatrixMarketFormat::Coordinate, MatrixMarketSymmetry::SkewSymmetric),
+            Err(MatrixError::NotSymmetric)
+        ));
+        assert!(CMatrix::<u32>::zero(2, 2)
+            .to_matrix_market(Vec::new(), MatrixMarketFormat::Array, MatrixMarketSymmetry::SkewSymmetric)
+            .is_ok());
+    }
+
+    #[test]
+    fn matrix_market_write_test() {
+        let a = cmat![2.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 2.0];
+        let mut buf = Vec::new();
+
+        a.to_matrix_market(&mut buf, MatrixMarketFormat::Coordinate, MatrixMarketSymmetry::Symmetric)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(buf.clone()).unwrap(),
+            "%%MatrixMarket matrix coordinate real symmetric\n3 3 5\n1 1 2.0\n2 1 -1.0\n2 2 2.0\n3 2 -1.0\n3 3 2.0\n"
+        );
+        assert_eq!(CMatrix::<f64>::from_matrix_market(&buf[..]).unwrap(), a);
+
+        let b = cmat![1, 2, 3; 4, 5, 6];
+        let mut buf = Vec::new();
+        b.to_matrix_market(&mut buf, MatrixMarketFormat::Array, MatrixMarketSymmetry::General)
+            .unwrap();
+        assert_eq!(CMatrix::<i32>::from_matrix_market(&buf[..]).unwrap(), b);
+
+        let mut c = b.clone();
+        c.transpose();
+        assert!(matches!(
+            (b * c).to_matrix_market(Vec::new(), MatrixMarketFormat::Array, MatrixMarketSymmetry::SkewSymmetric),
+            Err(MatrixError::NotSymmetric)
+        ));
+    }
+}
@@ -0,0 +1,285 @@
+pub mod normal_form {
+    extern crate num;
+
+    use self::num::{Integer, Signed};
+    use crate::{CMatrix, CMatrixTrait, Matrix, Scalar};
+
+    /// Hermite normal form of integer matrix: U * A = H.
+    /// H is in row echelon form with positive pivots, elements above every pivot are in [0, pivot).
+    /// U is unimodular, i.e. integer matrix with determinant 1 or -1
+    #[derive(Debug, Clone)]
+    pub struct HermiteNormalForm<T: Scalar> {
+        pub(crate) h: CMatrix<T>,
+        pub(crate) u: CMatrix<T>,
+    }
+
+    impl<T: Scalar> HermiteNormalForm<T> {
+        /// Returns matrix H in Hermite normal form
+        pub fn h(&self) -> CMatrix<T> {
+            self.h.clone()
+        }
+
+        /// Returns unimodular matrix U of size rows x rows
+        pub fn u(&self) -> CMatrix<T> {
+            self.u.clone()
+        }
+
+        /// Returns rank of matrix, i.e. amount of nonzero rows of H
+        pub fn rank(&self) -> usize {
+            (0..self.h.get_rows())
+                .filter(|&i| self.h.row(i).iter().any(|e| !e.is_zero()))
+                .count()
+        }
+    }
+
+    /// Smith normal form of integer matrix: U * A * V = D.
+    /// D is diagonal with nonnegative elements, every element of diagonal divides the next one.
+    /// U and V are unimodular
+    #[derive(Debug, Clone)]
+    pub struct SmithNormalForm<T: Scalar> {
+        pub(crate) d: CMatrix<T>,
+        pub(crate) u: CMatrix<T>,
+        pub(crate) v: CMatrix<T>,
+    }
+
+    impl<T: Scalar> SmithNormalForm<T> {
+        /// Returns diagonal matrix D of the same size as A
+        pub fn d(&self) -> CMatrix<T> {
+            self.d.clone()
+        }
+
+        /// Returns unimodular matrix U of size rows x rows
+        pub fn u(&self) -> CMatrix<T> {
+            self.u.clone()
+        }
+
+        /// Returns unimodular matrix V of size columns x columns
+        pub fn v(&self) -> CMatrix<T> {
+            self.v.clone()
+        }
+
+        /// Returns invariant factors: diagonal of D
+        pub fn invariant_factors(&self) -> Vec<T> {
+            let k = self.d.get_rows().min(self.d.get_columns());
+            (0..k).map(|i| self.d[(i, i)].clone()).collect()
+        }
+    }
+
+    impl<T: Scalar + Integer + Signed> CMatrix<T> {
+        /// Counts Hermite normal form of integer matrix with unimodular row operations
+        pub fn hermite_normal_form(&self) -> HermiteNormalForm<T> {
+            let (m, n) = (self.get_rows(), self.get_columns());
+            let mut h = self.clone();
+            let mut u = CMatrix::identity(m, m);
+            let mut r = 0;
+
+            for j in 0..n {
+                if r == m {
+                    break;
+                }
+
+                for i in r + 1..m {
+                    if h[(i, j)].is_zero() {
+                        continue;
+                    }
+
+                    let (a, b) = (h[(r, j)].clone(), h[(i, j)].clone());
+                    let e = a.extended_gcd(&b);
+                    let t = [e.x, e.y, T::zero() - b / e.gcd.clone(), a / e.gcd];
+                    combine_rows(&mut h, r, i, &t);
+                    combine_rows(&mut u, r, i, &t);
+                }
+
+                if h[(r, j)].is_zero() {
+                    continue;
+                }
+                if h[(r, j)].is_negative() {
+                    negate_row(&mut h, r);
+                    negate_row(&mut u, r);
+                }
+
+                for i in 0..r {
+                    let q = h[(i, j)].div_floor(&h[(r, j)]);
+                    subtract_row(&mut h, i, r, &q);
+                    subtract_row(&mut u, i, r, &q);
+                }
+                r += 1;
+            }
+
+            HermiteNormalForm { h, u }
+        }
+
+        /// Counts Smith normal form of integer matrix with unimodular row and column operations
+        pub fn smith_normal_form(&self) -> SmithNormalForm<T> {
+            let (m, n) = (self.get_rows(), self.get_columns());
+            let mut d = self.clone();
+            let mut u = CMatrix::identity(m, m);
+            let mut v = CMatrix::identity(n, n);
+
+            for t in 0..m.min(n) {
+                loop {
+                    // element with the smallest absolute value becomes pivot, remainders are smaller than it
+                    let mut pivot: Option<(usize, usize)> = None;
+                    for i in t..m {
+                        for j in t..n {
+                            let e = &d[(i, j)];
+                            if !e.is_zero() && pivot.map_or(true, |(pi, pj)| e.abs() < d[(pi, pj)].abs()) {
+                                pivot = Some((i, j));
+                            }
+                        }
+                    }
+                    let (pi, pj) = match pivot {
+                        Some(pivot) => pivot,
+                        None => return SmithNormalForm { d, u, v },
+                    };
+
+                    swap_rows(&mut d, t, pi);
+                    swap_rows(&mut u, t, pi);
+                    swap_columns(&mut d, t, pj);
+                    swap_columns(&mut v, t, pj);
+
+                    let mut reduced = true;
+                    for i in t + 1..m {
+                        let q = d[(i, t)].clone() / d[(t, t)].clone();
+                        subtract_row(&mut d, i, t, &q);
+                        subtract_row(&mut u, i, t, &q);
+                        reduced &= d[(i, t)].is_zero();
+                    }
+                    for j in t + 1..n {
+                        let q = d[(t, j)].clone() / d[(t, t)].clone();
+                        subtract_column(&mut d, j, t, &q);
+                        subtract_column(&mut v, j, t, &q);
+                        reduced &= d[(t, j)].is_zero();
+                    }
+                    if !reduced {
+                        continue;
+                    }
+
+                    // pivot must divide the rest of matrix, otherwise a remainder is brought into row t
+                    let rest = (t + 1..m).find(|&i| (t + 1..n).any(|j| !d[(i, j)].is_multiple_of(&d[(t, t)])));
+                    match rest {
+                        Some(i) => {
+                            let one = T::one();
+                            subtract_row(&mut d, t, i, &(T::zero() - one.clone()));
+                            subtract_row(&mut u, t, i, &(T::zero() - one));
+                        }
+                        None => break,
+                    }
+                }
+
+                if d[(t, t)].is_negative() {
+                    negate_row(&mut d, t);
+                    negate_row(&mut u, t);
+                }
+            }
+
+            SmithNormalForm { d, u, v }
+        }
+    }
+
+    /// Replaces rows r and i with t[0] * r + t[1] * i and t[2] * r + t[3] * i
+    fn combine_rows<T: Scalar>(m: &mut CMatrix<T>, r: usize, i: usize, t: &[T; 4]) {
+        for j in 0..m.get_columns() {
+            let (a, b) = (m[(r, j)].clone(), m[(i, j)].clone());
+            m[(r, j)] = t[0].clone() * a.clone() + t[1].clone() * b.clone();
+            m[(i, j)] = t[2].clone() * a + t[3].clone() * b;
+        }
+    }
+
+    /// Subtracts row r multiplied by q from row i
+    fn subtract_row<T: Scalar>(m: &mut CMatrix<T>, i: usize, r: usize, q: &T) {
+        if q.is_zero() {
+            return;
+        }
+        for j in 0..m.get_columns() {
+            m[(i, j)] = m[(i, j)].clone() - q.clone() * m[(r, j)].clone();
+        }
+    }
+
+    /// Subtracts column c multiplied by q from column j
+    fn subtract_column<T: Scalar>(m: &mut CMatrix<T>, j: usize, c: usize, q: &T) {
+        if q.is_zero() {
+            return;
+        }
+        for i in 0..m.get_rows() {
+            m[(i, j)] = m[(i, j)].clone() - q.clone() * m[(i, c)].clone();
+        }
+    }
+
+    fn negate_row<T: Scalar>(m: &mut CMatrix<T>, r: usize) {
+        for e in m[r].iter_mut() {
+            *e = T::zero() - e.clone();
+        }
+    }
+
+    fn swap_rows<T: Scalar>(m: &mut CMatrix<T>, a: usize, b: usize) {
+        let n = m.get_columns();
+        for j in 0..n {
+            m.as_mut_slice().swap(a * n + j, b * n + j);
+        }
+    }
+
+    fn swap_columns<T: Scalar>(m: &mut CMatrix<T>, a: usize, b: usize) {
+        let n = m.get_columns();
+        for i in 0..m.get_rows() {
+            m.as_mut_slice().swap(i * n + a, i * n + b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate num;
+
+    use self::num::bigint::BigInt;
+    use self::num::Signed;
+    use crate::{cmat, CMatrix, CMatrixTrait, Matrix};
+
+    #[test]
+    fn hermite_normal_form_test() {
+        let a: CMatrix<i64> = cmat![2, 3, 6, 2; 5, 6, 1, 6; 8, 3, 1, 1];
+        let hnf = a.hermite_normal_form();
+        let (h, u) = (hnf.h(), hnf.u());
+
+        assert_eq!(u.clone() * a, h);
+        assert_eq!(u.det().abs(), 1);
+        assert_eq!(h, cmat![1, 0, 50, -11; 0, 3, 28, -2; 0, 0, 61, -13]);
+        assert_eq!(hnf.rank(), 3);
+    }
+
+    #[test]
+    fn hermite_normal_form_rank_deficient_test() {
+        let a: CMatrix<i64> = cmat![2, 4; 3, 6; -4, -8];
+        let hnf = a.hermite_normal_form();
+
+        assert_eq!(hnf.h(), cmat![1, 2; 0, 0; 0, 0]);
+        assert_eq!(hnf.u() * a, hnf.h());
+        assert_eq!(hnf.u().det().abs(), 1);
+        assert_eq!(hnf.rank(), 1);
+    }
+
+    #[test]
+    fn smith_normal_form_test() {
+        let a: CMatrix<i64> = cmat![2, 4, 4; -6, 6, 12; 10, -4, -16];
+        let snf = a.smith_normal_form();
+
+        assert_eq!(snf.invariant_factors(), vec![2, 6, 12]);
+        assert_eq!(snf.u() * a * snf.v(), snf.d());
+        assert_eq!(snf.u().det().abs(), 1);
+        assert_eq!(snf.v().det().abs(), 1);
+    }
+
+    #[test]
+    fn smith_normal_form_bigint_test() {
+        let b = |n: i64| BigInt::from(n);
+        let a: CMatrix<BigInt> = cmat![b(6), b(4), b(0); b(4), b(6), b(2); b(2), b(2), b(8)];
+        let snf = a.smith_normal_form();
+
+        assert_eq!(snf.invariant_factors(), vec![b(2), b(2), b(38)]);
+        assert_eq!(snf.u() * a.clone() * snf.v(), snf.d());
+        assert_eq!(snf.d().det(), a.det().abs());
+
+        let z: CMatrix<i64> = cmat![0, 0; 0, 3; 0, 0];
+        assert_eq!(z.smith_normal_form().d(), cmat![3, 0; 0, 0; 0, 0]);
+    }
+}
@@ -0,0 +1,513 @@
+pub mod npy {
+    use crate::Scalar;
+    use crate::{CMatrix, MatrixError};
+    use std::convert::TryFrom;
+    use std::io::{Read, Write};
+
+    const MAGIC: &[u8] = b"\x93NUMPY";
+    const LOCAL_HEADER: u32 = 0x0403_4b50;
+    const CENTRAL_HEADER: u32 = 0x0201_4b50;
+    const END_OF_DIRECTORY: u32 = 0x0605_4b50;
+    const CRC_TABLE: [u32; 256] = crc_table();
+
+    /// Element type which can be stored in .npy file
+    pub trait NpyElement: Sized {
+        /// Little-endian NumPy type descriptor, e.g. `<f8`
+        const DESCR: &'static str;
+
+        /// Reads element from little-endian bytes
+        fn read_le(bytes: &[u8]) -> Self;
+
+        /// Appends little-endian bytes of element to `out`
+        fn write_le(&self, out: &mut Vec<u8>);
+    }
+
+    macro_rules! npy_element {
+        ($t:ty, $descr:expr) => {
+            impl NpyElement for $t {
+                const DESCR: &'static str = $descr;
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    let mut buf = [0; std::mem::size_of::<$t>()];
+                    buf.copy_from_slice(bytes);
+                    <$t>::from_le_bytes(buf)
+                }
+
+                fn write_le(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        };
+    }
+
+    npy_element!(f32, "<f4");
+    npy_element!(f64, "<f8");
+    npy_element!(i32, "<i4");
+    npy_element!(i64, "<i8");
+
+    impl<T: Scalar + NpyElement> CMatrix<T> {
+        /// Reads matrix from .npy data. Arrays of one dimension are read as one row, arrays with no dimensions as 1x1 matrix
+        pub fn from_npy<R: Read>(mut reader: R) -> Result<CMatrix<T>, MatrixError> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes)?;
+            parse_npy(&bytes)
+        }
+
+        /// Writes matrix as .npy data in C order
+        pub fn to_npy<W: Write>(&self, mut writer: W) -> Result<(), MatrixError> {
+            writer.write_all(&self.npy_bytes())?;
+            writer.flush()?;
+            Ok(())
+        }
+
+        /// Reads array `name` from .npz archive. Name may be given with or without `.npy` suffix.
+        /// Only stored (uncompressed) archives, as made by `numpy.savez`, are supported
+        pub fn from_npz<R: Read>(mut reader: R, name: &str) -> Result<CMatrix<T>, MatrixError> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes)?;
+
+            let name = if name.ends_with(".npy") {
+                name.to_string()
+            } else {
+                format!("{}.npy", name)
+            };
+
+            for (entry, data) in zip_entries(&bytes)? {
+                if entry == name {
+                    return parse_npy(data);
+                }
+            }
+            Err(MatrixError::InvalidNpy(format!("array '{}' is not found", name)))
+        }
+
+        /// Writes matrices as stored (uncompressed) .npz archive. Every matrix is saved under its name with `.npy` suffix
+        pub fn write_npz<W: Write>(mut writer: W, arrays: &[(&str, &CMatrix<T>)]) -> Result<(), MatrixError> {
+            let count = u16::try_from(arrays.len())
+                .map_err(|_| invalid(&format!("archive can't hold more than {} arrays", u16::MAX)))?;
+            let mut archive = Vec::new();
+            let mut directory = Vec::new();
+
+            for &(name, matrix) in arrays {
+                let name = format!("{}.npy", name);
+                let data = matrix.npy_bytes();
+                let offset = zip_u32(archive.len())?;
+                let (crc, size) = (crc32(&data), zip_u32(data.len())?);
+                let name_len =
+                    u16::try_from(name.len()).map_err(|_| invalid(&format!("name '{}' is too long", name)))?;
+
+                push_u32(&mut archive, LOCAL_HEADER);
+                push_entry_fields(&mut archive, crc, size, name_len);
+                push_u16(&mut archive, 0);
+                archive.extend_from_slice(name.as_bytes());
+                archive.extend_from_slice(&data);
+
+                push_u32(&mut directory, CENTRAL_HEADER);
+                push_u16(&mut directory, 20);
+                push_entry_fields(&mut directory, crc, size, name_len);
+                // Extra field, comment, disk number, internal and external attributes
+                push_u16(&mut directory, 0);
+                push_u16(&mut directory, 0);
+                push_u16(&mut directory, 0);
+                push_u16(&mut directory, 0);
+                push_u32(&mut directory, 0);
+                push_u32(&mut directory, offset);
+                directory.extend_from_slice(name.as_bytes());
+            }
+
+            let directory_offset = zip_u32(archive.len())?;
+            let directory_len = zip_u32(directory.len())?;
+            archive.extend_from_slice(&directory);
+
+            push_u32(&mut archive, END_OF_DIRECTORY);
+            push_u16(&mut archive, 0);
+            push_u16(&mut archive, 0);
+            push_u16(&mut archive, count);
+            push_u16(&mut archive, count);
+            push_u32(&mut archive, directory_len);
+            push_u32(&mut archive, directory_offset);
+            push_u16(&mut archive, 0);
+
+            writer.write_all(&archive)?;
+            writer.flush()?;
+            Ok(())
+        }
+
+        fn npy_bytes(&self) -> Vec<u8> {
+            let mut header = format!(
+                "{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+                T::DESCR,
+                self.rows,
+                self.columns
+            );
+            // Data starts at multiple of 64 bytes, header ends with newline
+            let unpadded = MAGIC.len() + 4 + header.len() + 1;
+            header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+            header.push('\n');
+
+            let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + header.len() + self.elems.len() * std::mem::size_of::<T>());
+            bytes.extend_from_slice(MAGIC);
+            bytes.extend_from_slice(&[1, 0]);
+            push_u16(&mut bytes, header.len() as u16);
+            bytes.extend_from_slice(header.as_bytes());
+
+            for e in self.elems.iter() {
+                e.write_le(&mut bytes);
+            }
+            bytes
+        }
+    }
+
+    /// Counts CRC-32 checksum used by zip
+    pub(crate) fn crc32(data: &[u8]) -> u32 {
+        let mut crc = !0u32;
+
+        for &b in data {
+            crc = CRC_TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8);
+        }
+        !crc
+    }
+
+    const fn crc_table() -> [u32; 256] {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+
+        while i < 256 {
+            let mut c = i as u32;
+            let mut k = 0;
+            while k < 8 {
+                c = if c & 1 != 0 { 0xedb8_8320 ^ (c >> 1) } else { c >> 1 };
+                k += 1;
+            }
+            table[i] = c;
+            i += 1;
+        }
+        table
+    }
+
+    fn parse_npy<T: Scalar + NpyElement>(
+        bytes: &[u8],
+    ) -> Result<CMatrix<T>, MatrixError> {
+        if bytes.len() < MAGIC.len() + 4 || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(invalid("missing magic string"));
+        }
+
+        let (header_len, header_start) = match bytes[MAGIC.len()] {
+            1 => (read_u16(bytes, 8)? as usize, 10),
+            2 | 3 => (read_u32(bytes, 8)? as usize, 12),
+            version => return Err(invalid(&format!("unsupported version {}", version))),
+        };
+        let data_start = header_start + header_len;
+        let header = match bytes.get(header_start..data_start).map(std::str::from_utf8) {
+            Some(Ok(header)) => header.replace('"', "'"),
+            _ => return Err(invalid("broken header")),
+        };
+
+        let descr = header_value(&header, "descr")?.trim_matches('\'');
+        if descr != T::DESCR {
+            return Err(MatrixError::DtypeMismatch {
+                expected: T::DESCR.to_string(),
+                found: descr.to_string(),
+            });
+        }
+
+        let fortran_order = match header_value(&header, "fortran_order")? {
+            "True" => true,
+            "False" => false,
+            other => return Err(invalid(&format!("wrong fortran_order '{}'", other))),
+        };
+
+        let shape = header_value(&header, "shape")?
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .split(',')
+            .map(|d| d.trim())
+            .filter(|d| !d.is_empty())
+            .map(|d| d.parse().map_err(|_| invalid(&format!("wrong dimension '{}'", d))))
+            .collect::<Result<Vec<usize>, MatrixError>>()?;
+        let (rows, columns) = match shape[..] {
+            [] => (1, 1),
+            [n] => (1, n),
+            [r, c] => (r, c),
+            _ => return Err(invalid(&format!("array has {} dimensions", shape.len()))),
+        };
+
+        let size = std::mem::size_of::<T>();
+        let data_end = rows
+            .checked_mul(columns)
+            .and_then(|len| len.checked_mul(size))
+            .and_then(|len| len.checked_add(data_start))
+            .ok_or_else(|| invalid(&format!("shape ({}, {}) is too large", rows, columns)))?;
+        let data = match bytes.get(data_start..data_end) {
+            Some(data) => data,
+            None => return Err(invalid("data is shorter than shape")),
+        };
+        let values: Vec<T> = data.chunks(size).map(T::read_le).collect();
+
+        let elems = if fortran_order {
+            (0..rows * columns).map(|k| values[(k % columns) * rows + k / columns].clone()).collect()
+        } else {
+            values
+        };
+
+        Ok(CMatrix { rows, columns, elems })
+    }
+
+    /// Returns value of `key` from header dictionary as text
+    fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, MatrixError> {
+        let missing = || invalid(&format!("header has no '{}'", key));
+        let start = header.find(&format!("'{}'", key)).ok_or_else(missing)? + key.len() + 2;
+        let rest = header[start..].trim_start();
+
+        if !rest.starts_with(':') {
+            return Err(missing());
+        }
+        let rest = rest[1..].trim_start();
+
+        let end = if rest.starts_with('(') {
+            rest.find(')').map(|i| i + 1)
+        } else if let Some(quoted) = rest.strip_prefix('\'') {
+            quoted.find('\'').map(|i| i + 2)
+        } else {
+            rest.find([',', '}'])
+        };
+        match end {
+            Some(end) => Ok(rest[..end].trim()),
+            None => Err(missing()),
+        }
+    }
+
+    /// Lists names and data of stored zip entries using the central directory
+    fn zip_entries(bytes: &[u8]) -> Result<Vec<(String, &[u8])>, MatrixError> {
+        let end = (0..bytes.len().saturating_sub(21))
+            .rev()
+            .find(|&i| read_u32(bytes, i).ok() == Some(END_OF_DIRECTORY))
+            .ok_or_else(|| invalid("zip end of central directory is not found"))?;
+
+        let count = read_u16(bytes, end + 10)? as usize;
+        let mut pos = read_u32(bytes, end + 16)? as usize;
+        let mut entries = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            if read_u32(bytes, pos)? != CENTRAL_HEADER {
+                return Err(invalid("broken zip central directory"));
+            }
+
+            let method = read_u16(bytes, pos + 10)?;
+            let crc = read_u32(bytes, pos + 16)?;
+            let mut size = read_u32(bytes, pos + 20)? as u64;
+            let name_len = read_u16(bytes, pos + 28)? as usize;
+            let extra_len = read_u16(bytes, pos + 30)? as usize;
+            let comment_len = read_u16(bytes, pos + 32)? as usize;
+            let mut offset = read_u32(bytes, pos + 42)? as u64;
+
+            let name = bytes
+                .get(pos + 46..pos + 46 + name_len)
+                .map(|n| String::from_utf8_lossy(n).into_owned())
+                .ok_or_else(|| invalid("broken zip entry name"))?;
+            let extra = bytes
+                .get(pos + 46 + name_len..pos + 46 + name_len + extra_len)
+                .ok_or_else(|| invalid("broken zip extra field"))?;
+            zip64_sizes(extra, read_u32(bytes, pos + 24)?, &mut size, &mut offset)?;
+
+            if method != 0 {
+                return Err(invalid(&format!("'{}' is compressed, only stored entries are supported", name)));
+            }
+
+            let local = offset as usize;
+            if read_u32(bytes, local)? != LOCAL_HEADER {
+                return Err(invalid("broken zip local header"));
+            }
+            let start = local + 30 + read_u16(bytes, local + 26)? as usize + read_u16(bytes, local + 28)? as usize;
+            let data = usize::try_from(size)
+                .ok()
+                .and_then(|size| start.checked_add(size))
+                .and_then(|end| bytes.get(start..end))
+                .ok_or_else(|| invalid(&format!("'{}' is truncated", name)))?;
+
+            if crc32(data) != crc {
+                return Err(invalid(&format!("'{}' has wrong checksum", name)));
+            }
+
+            entries.push((name, data));
+            pos += 46 + name_len + extra_len + comment_len;
+        }
+        Ok(entries)
+    }
+
+    /// Reads sizes and offset replaced by zip64 extra field. NumPy writes it for every entry
+    fn zip64_sizes(mut extra: &[u8], compressed: u32, size: &mut u64, offset: &mut u64) -> Result<(), MatrixError> {
+        while extra.len() >= 4 {
+            let id = read_u16(extra, 0)?;
+            let len = read_u16(extra, 2)? as usize;
+            let field = extra.get(4..4 + len).ok_or_else(|| invalid("broken zip extra field"))?;
+
+            if id == 1 {
+                let mut values = field.chunks(8).filter(|c| c.len() == 8).map(|c| {
+                    let mut b = [0; 8];
+                    b.copy_from_slice(c);
+                    u64::from_le_bytes(b)
+                });
+                if *size == u32::MAX as u64 {
+                    *size = values.next().ok_or_else(|| invalid("broken zip64 field"))?;
+                }
+                if compressed == u32::MAX {
+                    values.next();
+                }
+                if *offset == u32::MAX as u64 {
+                    *offset = values.next().ok_or_else(|| invalid("broken zip64 field"))?;
+                }
+            }
+            extra = &extra[4 + len..];
+        }
+        Ok(())
+    }
+
+    /// Writes fields shared by local and central zip headers, from version needed to extra field length
+    fn push_entry_fields(out: &mut Vec<u8>, crc: u32, size: u32, name_len: u16) {
+        push_u16(out, 20);
+        push_u16(out, 0);
+        push_u16(out, 0);
+        // Time and date: 1980-01-01 00:00
+        push_u16(out, 0);
+        push_u16(out, 0x21);
+        push_u32(out, crc);
+        push_u32(out, size);
+        push_u32(out, size);
+        push_u16(out, name_len);
+    }
+
+    /// Converts size or offset to zip field. Archives over 4 GiB need zip64, which isn't written
+    fn zip_u32(x: usize) -> Result<u32, MatrixError> {
+        u32::try_from(x).map_err(|_| invalid("archive is larger than 4 GiB"))
+    }
+
+    fn push_u16(out: &mut Vec<u8>, x: u16) {
+        out.extend_from_slice(&x.to_le_bytes());
+    }
+
+    fn push_u32(out: &mut Vec<u8>, x: u32) {
+        out.extend_from_slice(&x.to_le_bytes());
+    }
+
+    fn read_u16(bytes: &[u8], pos: usize) -> Result<u16, MatrixError> {
+        match bytes.get(pos..pos + 2) {
+            Some(b) => Ok(u16::from_le_bytes([b[0], b[1]])),
+            None => Err(invalid("unexpected end of data")),
+        }
+    }
+
+    fn read_u32(bytes: &[u8], pos: usize) -> Result<u32, MatrixError> {
+        match bytes.get(pos..pos + 4) {
+            Some(b) => Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]])),
+            None => Err(invalid("unexpected end of data")),
+        }
+    }
+
+    fn invalid(message: &str) -> MatrixError {
+        MatrixError::InvalidNpy(message.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::npy::npy::crc32;
+    use crate::{cmat, CMatrix, CMatrixTrait, Matrix, MatrixError};
+
+    fn npy(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+        let order = if fortran_order { "True" } else { "False" };
+        let header = format!("{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}\n", descr, order, shape);
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    #[test]
+    fn npy_roundtrip_test() {
+        let a = cmat![1.5, -2.0, 3.0; 4.0, 5.0, 6.25];
+        let mut buf = Vec::new();
+        a.to_npy(&mut buf).unwrap();
+
+        assert_eq!(&buf[..8], b"\x93NUMPY\x01\x00");
+        assert_eq!((buf.len() - 6 * 8) % 64, 0);
+        assert_eq!(CMatrix::<f64>::from_npy(&buf[..]).unwrap(), a);
+
+        let b = cmat![1, 2; -3, 4];
+        let mut buf = Vec::new();
+        b.to_npy(&mut buf).unwrap();
+        assert_eq!(CMatrix::<i32>::from_npy(&buf[..]).unwrap(), b);
+    }
+
+    #[test]
+    fn npy_order_test() {
+        let data: Vec<u8> = [1.0f32, 4.0, 2.0, 5.0, 3.0, 6.0].iter().flat_map(|x| x.to_le_bytes()).collect();
+        let fortran = npy("<f4", true, "(2, 3)", &data);
+        let c = npy("<f4", false, "(3, 2)", &data);
+
+        assert_eq!(CMatrix::<f32>::from_npy(&fortran[..]).unwrap(), cmat![1.0f32, 2.0, 3.0; 4.0, 5.0, 6.0]);
+        assert_eq!(CMatrix::<f32>::from_npy(&c[..]).unwrap(), cmat![1.0f32, 4.0; 2.0, 5.0; 3.0, 6.0]);
+        assert_eq!(CMatrix::<f32>::from_npy(&npy("<f4", false, "(3,)", &data[..12])[..]).unwrap().get_rows(), 1);
+    }
+
+    #[test]
+    fn npy_errors_test() {
+        let data: Vec<u8> = [1i64, 2].iter().flat_map(|x| x.to_le_bytes()).collect();
+
+        match CMatrix::<f64>::from_npy(&npy("<i8", false, "(1, 2)", &data)[..]) {
+            Err(MatrixError::DtypeMismatch { expected, found }) => {
+                assert_eq!(expected, "<f8");
+                assert_eq!(found, "<i8");
+            }
+            other => panic!("{:?}", other),
+        }
+        assert!(matches!(
+            CMatrix::<f64>::from_npy(&npy("<f8", false, "(2, 2)", &data)[..]),
+            Err(MatrixError::InvalidNpy(_))
+        ));
+        assert!(matches!(
+            CMatrix::<f64>::from_npy(&npy("<f8", false, "(1, 1, 2)", &data)[..]),
+            Err(MatrixError::InvalidNpy(_))
+        ));
+        assert!(matches!(CMatrix::<f64>::from_npy(&b"NUMPY"[..]), Err(MatrixError::InvalidNpy(_))));
+        assert!(matches!(
+            CMatrix::<f64>::from_npy(&npy("<f8", false, "(4294967296, 4294967296)", &data)[..]),
+            Err(MatrixError::InvalidNpy(_))
+        ));
+    }
+
+    #[test]
+    fn npz_test() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+
+        let a = cmat![1.0, 2.0; 3.0, 4.0];
+        let b = cmat![5.0, 6.0, 7.0];
+        let mut buf = Vec::new();
+        CMatrix::write_npz(&mut buf, &[("a", &a), ("b", &b)]).unwrap();
+
+        assert_eq!(CMatrix::<f64>::from_npz(&buf[..], "a").unwrap(), a);
+        assert_eq!(CMatrix::<f64>::from_npz(&buf[..], "b.npy").unwrap(), b);
+        assert!(matches!(CMatrix::<f64>::from_npz(&buf[..], "c"), Err(MatrixError::InvalidNpy(_))));
+        assert!(matches!(CMatrix::<f32>::from_npz(&buf[..], "a"), Err(MatrixError::DtypeMismatch { .. })));
+
+        // Last byte of "b" data is followed by two central directory entries and the end record
+        let last = buf.len() - 2 * (46 + 5) - 22 - 1;
+        buf[last] ^= 1;
+        assert!(matches!(CMatrix::<f64>::from_npz(&buf[..], "b"), Err(MatrixError::InvalidNpy(_))));
+    }
+
+    #[test]
+    fn npz_limits_test() {
+        let a = cmat![1.0];
+        let name = "a".repeat(70000);
+        assert!(matches!(
+            CMatrix::write_npz(Vec::new(), &[(name.as_str(), &a)]),
+            Err(MatrixError::InvalidNpy(_))
+        ));
+
+        let arrays = vec![("a", &a); 65536];
+        assert!(matches!(CMatrix::write_npz(Vec::new(), &arrays), Err(MatrixError::InvalidNpy(_))));
+        assert!(CMatrix::write_npz(Vec::new(), &arrays[1..]).is_ok());
+    }
+}
@@ -0,0 +1,295 @@
+pub mod qr {
+    extern crate num;
+
+    use self::num::Complex;
+    use crate::{CMatrix, CMatrixTrait, ComplexField, MatrixError, Matrix};
+
+    /// QR decomposition made with Householder reflections: A * P = Q * R.
+    /// Q is orthogonal (unitary for complex matrices), R is upper triangular, P is a column permutation (identity if pivoting is off)
+    #[derive(Debug, Clone)]
+    pub struct QR<T: ComplexField = f64> {
+        pub(crate) r: CMatrix<T>,
+        pub(crate) reflectors: Vec<Vec<T>>,
+        pub(crate) perm: Vec<usize>,
+    }
+
+    impl<T: ComplexField> QR<T> {
+        /// Factorizes matrix of size m x n. If `pivoting` is true, the column with the largest
+        /// remaining norm is moved to the front on every step, so diagonal of R is non-increasing
+        pub(crate) fn factorize(mut r: CMatrix<T>, pivoting: bool) -> QR<T> {
+            let (m, n) = (r.get_rows(), r.get_columns());
+            let mut perm: Vec<usize> = (0..n).collect();
+            let mut reflectors = Vec::new();
+
+            for k in 0..m.min(n) {
+                if pivoting {
+                    let norm = |j: usize| (k..m).map(|i| r[(i, j)].modulus().powi(2)).sum::<f64>();
+                    let mut max_col = k;
+                    for j in k + 1..n {
+                        if norm(j) > norm(max_col) {
+                            max_col = j;
+                        }
+                    }
+
+                    if max_col != k {
+                        for i in 0..m {
+                            r.as_mut_slice().swap(i * n + k, i * n + max_col);
+                        }
+                        perm.swap(k, max_col);
+                    }
+                }
+                // the last row has nothing below diagonal, so only the pivot is chosen
+                if k + 1 == m {
+                    break;
+                }
+
+                let mut v: Vec<T> = (k..m).map(|i| r[(i, k)].clone()).collect();
+                let x_norm = v.iter().map(|e| e.modulus().powi(2)).sum::<f64>().sqrt();
+
+                if x_norm == 0.0 {
+                    reflectors.push(vec![T::zero(); m - k]);
+                    continue;
+                }
+
+                // alpha has phase opposite to the leading element, so v[0] - alpha doesn't cancel
+                let alpha = if v[0].is_zero() {
+                    T::from_complex(Complex::new(x_norm, 0.0))
+                } else {
+                    let lead = v[0].to_complex();
+                    T::from_complex(-lead / lead.norm() * x_norm)
+                };
+                v[0] = v[0].clone() - alpha.clone();
+                let v_norm = v.iter().map(|e| e.modulus().powi(2)).sum::<f64>().sqrt();
+                let v_norm = T::from_complex(Complex::new(v_norm, 0.0));
+                for e in v.iter_mut() {
+                    *e = e.clone() / v_norm.clone();
+                }
+
+                for j in k..n {
+                    reflect(&v, r.as_mut_slice(), k, j, n);
+                }
+                r[(k, k)] = alpha;
+                for i in k + 1..m {
+                    r[(i, k)] = T::zero();
+                }
+
+                reflectors.push(v);
+            }
+
+            QR { r, reflectors, perm }
+        }
+
+        /// Returns full orthogonal (unitary) matrix Q of size m x m
+        pub fn q(&self) -> CMatrix<T> {
+            self.form_q(self.r.get_rows())
+        }
+
+        /// Returns full upper triangular matrix R of size m x n
+        pub fn r(&self) -> CMatrix<T> {
+            self.r.clone()
+        }
+
+        /// Returns first min(m, n) columns of Q
+        pub fn thin_q(&self) -> CMatrix<T> {
+            self.form_q(self.r.get_rows().min(self.r.get_columns()))
+        }
+
+        /// Returns first min(m, n) rows of R
+        pub fn thin_r(&self) -> CMatrix<T> {
+            let (m, n) = (self.r.get_rows(), self.r.get_columns());
+            let k = m.min(n);
+
+            CMatrix {
+                rows: k,
+                columns: n,
+                elems: self.r.as_slice()[..k * n].to_vec(),
+            }
+        }
+
+        /// Returns column permutation: column j of A * P is column `permutation()[j]` of A
+        pub fn permutation(&self) -> &[usize] {
+            &self.perm
+        }
+
+        /// Returns permutation matrix P
+        pub fn p(&self) -> CMatrix<T> {
+            let n = self.perm.len();
+            let mut p = CMatrix::zero(n, n);
+
+            for (j, &i) in self.perm.iter().enumerate() {
+                p[(i, j)] = T::one();
+            }
+            p
+        }
+
+        /// Counts numerical rank from diagonal of R. Reliable only for factorization with pivoting
+        pub fn rank(&self) -> usize {
+            let (m, n) = (self.r.get_rows(), self.r.get_columns());
+            let max = (0..m.min(n)).fold(0.0, |acc: f64, i| acc.max(self.r[(i, i)].modulus()));
+            let tol = max * m.max(n) as f64 * f64::EPSILON;
+
+            (0..m.min(n)).filter(|&i| self.r[(i, i)].modulus() > tol).count()
+        }
+
+        /// Solves A * X = B in the least squares sense. A must have rows >= columns and full column rank
+        pub fn solve<B: Matrix<T>>(&self, rhs: &B) -> CMatrix<T> {
+            match self.try_solve(rhs) {
+                Ok(x) => x,
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        /// Try to solve A * X = B in the least squares sense. A must have rows >= columns and full column rank
+        pub fn try_solve<B: Matrix<T>>(&self, rhs: &B) -> Result<CMatrix<T>, MatrixError> {
+            let (m, n) = (self.r.get_rows(), self.r.get_columns());
+
+            if rhs.get_rows() != m {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (m, rhs.get_columns()),
+                    found: (rhs.get_rows(), rhs.get_columns()),
+                });
+            }
+            if m < n || self.rank() < n {
+                return Err(MatrixError::Singular);
+            }
+
+            let k = rhs.get_columns();
+            let mut y = rhs.as_slice().to_vec();
+            for (c, v) in self.reflectors.iter().enumerate() {
+                for j in 0..k {
+                    reflect(v, &mut y, c, j, k);
+                }
+            }
+
+            for i in (0..n).rev() {
+                for j in i + 1..n {
+                    let u = &self.r[(i, j)];
+                    for c in 0..k {
+                        y[i * k + c] = y[i * k + c].clone() - u.clone() * y[j * k + c].clone();
+                    }
+                }
+                let pivot = &self.r[(i, i)];
+                for c in 0..k {
+                    y[i * k + c] = y[i * k + c].clone() / pivot.clone();
+                }
+            }
+
+            let mut x = CMatrix::zero(n, k);
+            for (i, &p) in self.perm.iter().enumerate() {
+                x[p].clone_from_slice(&y[i * k..(i + 1) * k]);
+            }
+            Ok(x)
+        }
+
+        /// Applies reflectors to the first `cols` columns of identity matrix
+        fn form_q(&self, cols: usize) -> CMatrix<T> {
+            let mut q = CMatrix::identity(self.r.get_rows(), cols);
+
+            for (k, v) in self.reflectors.iter().enumerate().rev() {
+                for j in 0..cols {
+                    reflect(v, q.as_mut_slice(), k, j, cols);
+                }
+            }
+            q
+        }
+    }
+
+    /// Applies reflection I - 2 * v * v^H to rows k.. of column j of row-major buffer with `width` columns
+    fn reflect<T: ComplexField>(v: &[T], a: &mut [T], k: usize, j: usize, width: usize) {
+        let dot = v
+            .iter()
+            .enumerate()
+            .fold(T::zero(), |acc, (i, e)| acc + e.conjugate() * a[(k + i) * width + j].clone());
+        let twice = dot.clone() + dot;
+
+        for (i, e) in v.iter().enumerate() {
+            a[(k + i) * width + j] = a[(k + i) * width + j].clone() - twice.clone() * e.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate num;
+
+    use self::num::Complex;
+    use crate::{cmat, CMatrix, CMatrixTrait, CVector, Matrix, Matrix3};
+
+    #[test]
+    fn qr_factors_test() {
+        let a: CMatrix<f64> = cmat![12.0, -51.0, 4.0; 6.0, 167.0, -68.0; -4.0, 24.0, -41.0];
+        let qr = a.qr();
+        let (q, r) = (qr.q(), qr.r());
+
+        let mut qt = q.clone();
+        qt.transpose();
+
+        assert!((q.clone() * r.clone() - a).norm() < 1e-10);
+        assert!((qt * q - CMatrix::identity(3, 3)).norm() < 1e-12);
+        assert!((0..3).all(|i| (0..i).all(|j| r[(i, j)] == 0.0)));
+    }
+
+    #[test]
+    fn qr_thin_test() {
+        let a = cmat![1, 2; 3, 4; 5, 6; 7, 8];
+        let qr = a.qr();
+
+        assert_eq!(qr.q().get_rows(), 4);
+        assert_eq!(qr.q().get_columns(), 4);
+        assert_eq!(qr.thin_q().get_columns(), 2);
+        assert_eq!(qr.thin_r().get_rows(), 2);
+        assert!((qr.thin_q() * qr.thin_r() - a.to_f64()).norm() < 1e-12);
+        assert!((qr.q() * qr.r() - a.to_f64()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn qr_pivoting_test() {
+        let a = Matrix3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0);
+        let qr = a.qr_pivoted();
+
+        assert_eq!(qr.rank(), 2);
+        assert!((qr.q() * qr.r() - a.to_f64() * qr.p()).norm() < 1e-12);
+        assert!(qr.r()[(0, 0)].abs() >= qr.r()[(1, 1)].abs());
+    }
+
+    #[test]
+    fn qr_pivoting_wide_test() {
+        let a = cmat![1.0, 0.0, 0.0; 0.0, 0.0, 1.0];
+        let qr = a.qr_pivoted();
+
+        assert_eq!(qr.rank(), 2);
+        assert!((qr.q() * qr.r() - a * qr.p()).norm() < 1e-12);
+
+        let row = cmat![0.0, 0.0, 3.0, 1.0];
+        let qr = row.qr_pivoted();
+
+        assert_eq!(qr.rank(), 1);
+        assert_eq!(qr.permutation()[0], 2);
+        assert!((qr.q() * qr.r() - row * qr.p()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn qr_least_squares_test() {
+        // y = 1 + 2x fitted through exact points
+        let a: CMatrix<f64> = cmat![1.0, 0.0; 1.0, 1.0; 1.0, 2.0; 1.0, 3.0];
+        let x = a.qr().solve(&CVector::new(vec![1.0, 3.0, 5.0, 7.0]));
+
+        assert!((x - cmat![1.0; 2.0]).norm() < 1e-12);
+        assert!(cmat![1.0, 2.0; 2.0, 4.0].qr().try_solve(&CVector::new(vec![1.0, 2.0])).is_err());
+    }
+
+    #[test]
+    fn qr_complex_test() {
+        let c = |re: f64, im: f64| Complex::new(re, im);
+        let a: CMatrix<Complex<f64>> = cmat![c(1.0, 1.0), c(2.0, 0.0); c(0.0, -1.0), c(1.0, 3.0); c(2.0, 0.0), c(0.0, 1.0)];
+        let qr = a.complex_qr();
+        let (q, r) = (qr.q(), qr.r());
+
+        assert!(q.is_unitary());
+        assert!((q * r.clone() - a.clone()).norm() < 1e-12);
+        assert!((0..3).all(|i| (0..i.min(2)).all(|j| r[(i, j)] == c(0.0, 0.0))));
+
+        let x = a.complex_qr_pivoted().solve(&(a.clone() * cmat![c(1.0, 0.0); c(0.0, 1.0)]));
+        assert!((x - cmat![c(1.0, 0.0); c(0.0, 1.0)]).norm() < 1e-12);
+    }
+}
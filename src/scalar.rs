@@ -0,0 +1,256 @@
+pub mod scalar {
+    extern crate num;
+
+    use self::num::bigint::{BigInt, BigUint};
+    use self::num::rational::Ratio;
+    use self::num::{Complex, Integer, Num, ToPrimitive, Zero};
+
+    /// Element of a ring: supports +, -, * with zero and one. Every matrix element is a `Scalar`.
+    /// Division of ring elements may be inexact, e.g. integer division
+    pub trait Scalar: Num + Clone + Default + PartialEq + std::str::FromStr + std::fmt::Debug {
+        /// `ExactDivision` for fields, `TruncatedDivision` otherwise. `Field` requires `ExactDivision`,
+        /// so methods bounded only by `Scalar` can choose between Gauss and fraction-free elimination
+        type Division: DivisionKind;
+
+        /// Absolute value of element as f64. Used to choose pivots and to count norms
+        fn modulus(&self) -> f64;
+
+        /// Returns -self, or None if it can't be represented, e.g. for nonzero unsigned integers
+        fn checked_neg(&self) -> Option<Self>;
+
+        /// Relative precision of element type. Zero for exact types, so rank-revealing methods compare with zero exactly
+        fn epsilon() -> f64 {
+            0.0
+        }
+    }
+
+    /// Kind of division of scalar type
+    pub trait DivisionKind {
+        /// True if division is exact
+        const EXACT: bool;
+    }
+
+    /// Division of field elements: (a / b) * b == a
+    pub enum ExactDivision {}
+
+    /// Division which drops remainder, like integer division
+    pub enum TruncatedDivision {}
+
+    impl DivisionKind for ExactDivision {
+        const EXACT: bool = true;
+    }
+
+    impl DivisionKind for TruncatedDivision {
+        const EXACT: bool = false;
+    }
+
+    /// Scalar with exact division, like f64, Complex<f64> or Ratio<BigInt>
+    pub trait Field: Scalar<Division = ExactDivision> {}
+
+    /// Ordered scalar which can be converted to f64, like i64, f64 or BigInt
+    pub trait Real: Scalar + PartialOrd + ToPrimitive {}
+
+    impl<T: Scalar + PartialOrd + ToPrimitive> Real for T {}
+
+    /// Field with complex conjugation. Real floats are complex fields with identity conjugation
+    pub trait ComplexField: Field {
+        /// Returns complex conjugate of element
+        fn conjugate(&self) -> Self;
+
+        /// Converts element to Complex<f64>
+        fn to_complex(&self) -> Complex<f64>;
+
+        /// Makes element from Complex<f64>. Real types drop imaginary part
+        fn from_complex(z: Complex<f64>) -> Self;
+    }
+
+    macro_rules! int_scalar {
+        ($($t:ty),*) => {
+            $(
+                impl Scalar for $t {
+                    type Division = TruncatedDivision;
+
+                    fn modulus(&self) -> f64 {
+                        (*self as f64).abs()
+                    }
+
+                    fn checked_neg(&self) -> Option<Self> {
+                        <$t>::checked_neg(*self)
+                    }
+                }
+            )*
+        };
+    }
+
+    macro_rules! float_scalar {
+        ($($t:ty),*) => {
+            $(
+                impl Scalar for $t {
+                    type Division = ExactDivision;
+
+                    fn modulus(&self) -> f64 {
+                        self.abs() as f64
+                    }
+
+                    fn checked_neg(&self) -> Option<Self> {
+                        Some(-*self)
+                    }
+
+                    fn epsilon() -> f64 {
+                        <$t>::EPSILON as f64
+                    }
+                }
+
+                impl Field for $t {}
+
+                impl ComplexField for $t {
+                    fn conjugate(&self) -> Self {
+                        *self
+                    }
+
+                    fn to_complex(&self) -> Complex<f64> {
+                        Complex::new(*self as f64, 0.0)
+                    }
+
+                    fn from_complex(z: Complex<f64>) -> Self {
+                        z.re as $t
+                    }
+                }
+
+                impl Scalar for Complex<$t> {
+                    type Division = ExactDivision;
+
+                    fn modulus(&self) -> f64 {
+                        self.norm() as f64
+                    }
+
+                    fn checked_neg(&self) -> Option<Self> {
+                        Some(-*self)
+                    }
+
+                    fn epsilon() -> f64 {
+                        <$t>::EPSILON as f64
+                    }
+                }
+
+                impl Field for Complex<$t> {}
+
+                impl ComplexField for Complex<$t> {
+                    fn conjugate(&self) -> Self {
+                        self.conj()
+                    }
+
+                    fn to_complex(&self) -> Complex<f64> {
+                        Complex::new(self.re as f64, self.im as f64)
+                    }
+
+                    fn from_complex(z: Complex<f64>) -> Self {
+                        Complex::new(z.re as $t, z.im as $t)
+                    }
+                }
+            )*
+        };
+    }
+
+    int_scalar!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    float_scalar!(f32, f64);
+
+    impl Scalar for BigInt {
+        type Division = TruncatedDivision;
+
+        fn modulus(&self) -> f64 {
+            self.to_f64().map_or(f64::INFINITY, f64::abs)
+        }
+
+        fn checked_neg(&self) -> Option<Self> {
+            Some(-self)
+        }
+    }
+
+    impl Scalar for BigUint {
+        type Division = TruncatedDivision;
+
+        fn modulus(&self) -> f64 {
+            self.to_f64().unwrap_or(f64::INFINITY)
+        }
+
+        fn checked_neg(&self) -> Option<Self> {
+            if self.is_zero() {
+                Some(BigUint::zero())
+            } else {
+                None
+            }
+        }
+    }
+
+    impl<T: Scalar + Integer> Scalar for Ratio<T> {
+        type Division = ExactDivision;
+
+        fn modulus(&self) -> f64 {
+            self.numer().modulus() / self.denom().modulus()
+        }
+
+        fn checked_neg(&self) -> Option<Self> {
+            self.numer().checked_neg().map(|n| Ratio::new_raw(n, self.denom().clone()))
+        }
+    }
+
+    impl<T: Scalar + Integer> Field for Ratio<T> {}
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate num;
+
+    use self::num::bigint::{BigInt, BigUint};
+    use self::num::rational::Ratio;
+    use self::num::Complex;
+    use crate::{ComplexField, DivisionKind, Scalar};
+
+    #[test]
+    fn modulus_test() {
+        assert_eq!((-3i64).modulus(), 3.0);
+        assert_eq!((-2.5f32).modulus(), 2.5);
+        assert_eq!(Complex::new(3.0, -4.0).modulus(), 5.0);
+        assert_eq!(BigInt::from(-7).modulus(), 7.0);
+        assert_eq!(Ratio::new(BigInt::from(-3), BigInt::from(4)).modulus(), 0.75);
+        assert_eq!(f32::epsilon(), f32::EPSILON as f64);
+        assert_eq!(Complex::<f64>::epsilon(), f64::EPSILON);
+        assert_eq!(<Ratio<BigInt>>::epsilon(), 0.0);
+    }
+
+    #[test]
+    fn checked_neg_test() {
+        assert_eq!(Scalar::checked_neg(&3i32), Some(-3));
+        assert_eq!(Scalar::checked_neg(&i8::MIN), None);
+        assert_eq!(Scalar::checked_neg(&0u32), Some(0));
+        assert_eq!(Scalar::checked_neg(&2u32), None);
+        assert_eq!(Scalar::checked_neg(&Complex::new(1.0, -2.0)), Some(Complex::new(-1.0, 2.0)));
+        assert_eq!(Scalar::checked_neg(&BigUint::from(2u32)), None);
+        assert_eq!(Scalar::checked_neg(&Ratio::new(1i64, 2)), Some(Ratio::new(-1, 2)));
+    }
+
+    #[test]
+    fn exact_division_test() {
+        fn exact<T: Scalar>() -> bool {
+            <T::Division as DivisionKind>::EXACT
+        }
+
+        assert!(exact::<f32>());
+        assert!(exact::<Complex<f64>>());
+        assert!(exact::<Ratio<i64>>());
+        assert!(!exact::<i64>());
+        assert!(!exact::<u32>());
+        assert!(!exact::<BigInt>());
+    }
+
+    #[test]
+    fn complex_field_test() {
+        let z = Complex::new(1.0f32, 2.0);
+
+        assert_eq!(z.conjugate(), Complex::new(1.0, -2.0));
+        assert_eq!(z.to_complex(), Complex::new(1.0f64, 2.0));
+        assert_eq!(f64::from_complex(Complex::new(2.0, 5.0)), 2.0);
+        assert_eq!(3.0f64.conjugate(), 3.0);
+    }
+}
@@ -0,0 +1,176 @@
+pub mod serialization {
+    extern crate serde;
+
+    use self::serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+    use self::serde::ser::{Serialize, SerializeStruct, Serializer};
+    use crate::{CMatrix, MatrixError, SMatrix, Scalar};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    const FIELDS: &[&str] = &["rows", "cols", "data"];
+
+    /// Matrix as it is serialized: sizes and elements in row-major order
+    struct Raw<T> {
+        rows: usize,
+        cols: usize,
+        data: Vec<T>,
+    }
+
+    impl<T> Raw<T> {
+        /// Checks that amount of elements fits sizes and that sizes are equal to `expected` if it is given
+        fn validate<E: de::Error>(self, expected: Option<(usize, usize)>) -> Result<Self, E> {
+            if let Some(expected) = expected {
+                if (self.rows, self.cols) != expected {
+                    return Err(E::custom(MatrixError::DimensionMismatch {
+                        expected,
+                        found: (self.rows, self.cols),
+                    }));
+                }
+            }
+
+            if self.rows.checked_mul(self.cols) != Some(self.data.len()) {
+                return Err(E::custom(format!(
+                    "matrix {}x{} can't have {} elements",
+                    self.rows,
+                    self.cols,
+                    self.data.len()
+                )));
+            }
+            Ok(self)
+        }
+    }
+
+    fn serialize_raw<S: Serializer, T: Serialize>(
+        serializer: S,
+        name: &'static str,
+        rows: usize,
+        cols: usize,
+        data: &[T],
+    ) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct(name, FIELDS.len())?;
+        state.serialize_field("rows", &rows)?;
+        state.serialize_field("cols", &cols)?;
+        state.serialize_field("data", data)?;
+        state.end()
+    }
+
+    struct RawVisitor<T>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for RawVisitor<T> {
+        type Value = Raw<T>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("matrix as {rows, cols, data}")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Raw<T>, A::Error> {
+            let rows = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+            let cols = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+            let data = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(2, &self))?;
+            Ok(Raw { rows, cols, data })
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Raw<T>, A::Error> {
+            let (mut rows, mut cols, mut data) = (None, None, None);
+
+            while let Some(key) = map.next_key::<String>()? {
+                match key.as_str() {
+                    "rows" if rows.is_none() => rows = Some(map.next_value()?),
+                    "cols" if cols.is_none() => cols = Some(map.next_value()?),
+                    "data" if data.is_none() => data = Some(map.next_value()?),
+                    "rows" | "cols" | "data" => return Err(de::Error::custom(format!("duplicate field `{}`", key))),
+                    _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                }
+            }
+
+            Ok(Raw {
+                rows: rows.ok_or_else(|| de::Error::missing_field("rows"))?,
+                cols: cols.ok_or_else(|| de::Error::missing_field("cols"))?,
+                data: data.ok_or_else(|| de::Error::missing_field("data"))?,
+            })
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for Raw<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_struct("Matrix", FIELDS, RawVisitor(PhantomData))
+        }
+    }
+
+    impl<T: Scalar + Serialize> Serialize for CMatrix<T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize_raw(serializer, "CMatrix", self.rows, self.columns, &self.elems)
+        }
+    }
+
+    impl<'de, T: Scalar + Deserialize<'de>> Deserialize<'de> for CMatrix<T> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let raw = Raw::deserialize(deserializer)?.validate(None)?;
+
+            Ok(CMatrix {
+                rows: raw.rows,
+                columns: raw.cols,
+                elems: raw.data,
+            })
+        }
+    }
+
+    impl<T: Scalar + Serialize, const R: usize, const C: usize> Serialize for SMatrix<T, R, C> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let data: Vec<T> = self.elems.iter().flat_map(|row| row.iter().cloned()).collect();
+            serialize_raw(serializer, "SMatrix", R, C, &data)
+        }
+    }
+
+    impl<'de, T: Scalar + Deserialize<'de>, const R: usize, const C: usize> Deserialize<'de> for SMatrix<T, R, C> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let raw = Raw::deserialize(deserializer)?.validate(Some((R, C)))?;
+            let mut m = SMatrix::default();
+
+            for (i, e) in raw.data.into_iter().enumerate() {
+                m.elems[i / C][i % C] = e;
+            }
+            Ok(m)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+
+    use crate::{cmat, CMatrix, CMatrixTrait, Matrix, Matrix2, Matrix3};
+
+    #[test]
+    fn serde_cmatrix_test() {
+        let m = cmat![1.5, -2.0, 3.0; 4.0, 5.0, 6.0];
+        let json = serde_json::to_string(&m).unwrap();
+
+        assert_eq!(json, r#"{"rows":2,"cols":3,"data":[1.5,-2.0,3.0,4.0,5.0,6.0]}"#);
+        assert_eq!(serde_json::from_str::<CMatrix<f64>>(&json).unwrap(), m);
+        assert_eq!(serde_json::from_str::<CMatrix<i32>>("[1, 2, [7, 8]]").unwrap(), cmat![7, 8]);
+    }
+
+    #[test]
+    fn serde_smatrix_test() {
+        let m = Matrix2::new(1, 2, 3, 4);
+        let json = serde_json::to_string(&m).unwrap();
+
+        assert_eq!(json, r#"{"rows":2,"cols":2,"data":[1,2,3,4]}"#);
+        assert_eq!(serde_json::from_str::<Matrix2<i32>>(&json).unwrap(), m);
+
+        let m3 = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        assert_eq!(serde_json::from_str::<Matrix3<f64>>(&serde_json::to_string(&m3).unwrap()).unwrap(), m3);
+    }
+
+    #[test]
+    fn serde_validation_test() {
+        let m3 = r#"{"rows":3,"cols":3,"data":[1,2,3,4,5,6,7,8,9]}"#;
+
+        assert!(serde_json::from_str::<Matrix2<i32>>(m3).is_err());
+        assert!(serde_json::from_str::<Matrix3<i32>>(m3).is_ok());
+        assert!(serde_json::from_str::<CMatrix<i32>>(r#"{"rows":2,"cols":2,"data":[1,2,3]}"#).is_err());
+        assert!(serde_json::from_str::<CMatrix<i32>>(r#"{"rows":1,"data":[1]}"#).is_err());
+        assert!(serde_json::from_str::<CMatrix<i32>>(r#"{"rows":1,"cols":1,"data":[1],"extra":0}"#).is_err());
+    }
+}
@@ -0,0 +1,238 @@
+pub mod smatrix {
+    use crate::MatrixError;
+    use crate::matrix::matrix::{flatten_rows, parse_rows, Transposable};
+
+    use std::io::Read;
+
+    use crate::Scalar;
+    pub use crate::cmatrix::cmatrix::CMatrix;
+    pub use crate::matrix::matrix::Matrix;
+    pub use crate::matrix23_trait::matrix23::Matrix23;
+
+    /// Matrix with R rows and C columns, known at compile time. Elements are kept inline, without heap allocation
+    #[derive(Debug, Clone, Eq)]
+    pub struct SMatrix<T: Scalar, const R: usize, const C: usize> {
+        pub(crate) elems: [[T; C]; R],
+    }
+
+    impl<T: Scalar, const R: usize, const C: usize> SMatrix<T, R, C> {
+        /// Creates a matrix from array of rows
+        pub fn from_array(elems: [[T; C]; R]) -> Self {
+            SMatrix { elems }
+        }
+
+        /// Returns rows of matrix as array
+        pub fn as_array(&self) -> &[[T; C]; R] {
+            &self.elems
+        }
+
+        /// Creates a matrix from CMatrix. Panics if sizes are different
+        pub fn from_cmatrix(c: CMatrix<T>) -> Self {
+            match SMatrix::try_from_cmatrix(c) {
+                Ok(m) => m,
+                Err(e) => panic!("{}", e),
+            }
+        }
+
+        /// Try to create a matrix from CMatrix. Returns an error if sizes are different
+        pub fn try_from_cmatrix(c: CMatrix<T>) -> Result<Self, MatrixError> {
+            if c.get_rows() != R || c.get_columns() != C {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (R, C),
+                    found: (c.get_rows(), c.get_columns()),
+                });
+            }
+
+            let mut m = SMatrix::zero();
+            m.as_mut_slice().clone_from_slice(c.as_slice());
+            Ok(m)
+        }
+    }
+
+    impl<T: Scalar, const R: usize, const C: usize> Default for SMatrix<T, R, C> {
+        fn default() -> Self {
+            SMatrix {
+                elems: std::array::from_fn(|_| std::array::from_fn(|_| T::default())),
+            }
+        }
+    }
+
+    impl<T: Scalar, const R: usize, const C: usize> Matrix23<T> for SMatrix<T, R, C> {
+        fn zero() -> Self {
+            SMatrix::from_element(T::zero())
+        }
+
+        fn one() -> Self {
+            SMatrix::from_element(T::one())
+        }
+
+        fn identity() -> Self {
+            SMatrix::from_diagonal(T::one())
+        }
+
+        fn read_from<Rd: Read>(mut reader: Rd, delimiter: char) -> Result<Self, MatrixError> {
+            let mut s = String::new();
+            reader.read_to_string(&mut s)?;
+
+            let (rows, columns, elems) = parse_rows(&s, delimiter)?;
+
+            // files written before row-per-line format keep all elements in one line
+            if (rows, columns) != (R, C) && !(rows == 1 && columns == R * C) {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (R, C),
+                    found: (rows, columns),
+                });
+            }
+
+            let mut m = SMatrix::zero();
+            m.as_mut_slice().clone_from_slice(&elems);
+            Ok(m)
+        }
+
+        fn from_element(e: T) -> Self {
+            SMatrix {
+                elems: std::array::from_fn(|_| std::array::from_fn(|_| e.clone())),
+            }
+        }
+
+        fn from_vec_as_columns(v: Vec<T>) -> Self {
+            if v.len() > R {
+                panic!("Too much elements in vector!");
+            }
+
+            let mut m = SMatrix::zero();
+            for (row, e) in m.elems.iter_mut().zip(v) {
+                *row = std::array::from_fn(|_| e.clone());
+            }
+            m
+        }
+
+        fn from_vec_as_rows(v: Vec<T>) -> Self {
+            if v.len() > C {
+                panic!("Too much elements in vector!");
+            }
+
+            let mut m = SMatrix::zero();
+            for row in m.elems.iter_mut() {
+                row[..v.len()].clone_from_slice(&v);
+            }
+            m
+        }
+
+        fn from_diagonal(element: T) -> Self {
+            let mut m = SMatrix::zero();
+
+            for i in 0..R.min(C) {
+                m.elems[i][i] = element.clone();
+            }
+            m
+        }
+
+        fn to_cmatrix(self) -> CMatrix<T> {
+            CMatrix {
+                rows: R,
+                columns: C,
+                elems: self.as_slice().to_vec(),
+            }
+        }
+    }
+
+    impl<T: Scalar, const N: usize> Transposable for SMatrix<T, N, N> {}
+
+    impl<T: Scalar, const R: usize, const C: usize> Matrix<T> for SMatrix<T, R, C> {
+        fn check_size(&self) {}
+
+        fn get_columns(&self) -> usize {
+            C
+        }
+
+        fn get_rows(&self) -> usize {
+            R
+        }
+
+        fn set_elements(&mut self, v: Vec<Vec<T>>) {
+            if v.len() == R && v.iter().all(|row| row.len() == C) {
+                let (_, _, elems) = flatten_rows(v);
+                self.as_mut_slice().clone_from_slice(&elems);
+            } else {
+                panic!("Can't make {}x{} matrix from this elements! Wrong size maybe?", R, C);
+            }
+        }
+
+        fn as_slice(&self) -> &[T] {
+            self.elems.as_flattened()
+        }
+
+        fn as_mut_slice(&mut self) -> &mut [T] {
+            self.elems.as_flattened_mut()
+        }
+
+        fn into_elements(self) -> Vec<T> {
+            self.as_slice().to_vec()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CMatrix, CMatrixTrait, Matrix, Matrix2, Matrix23, MatrixError, SMatrix};
+
+    #[test]
+    fn smatrix_non_square_mul_test() {
+        let a = SMatrix::from_array([[1, 2, 3], [4, 5, 6]]);
+        let b = SMatrix::from_array([[1, 2], [3, 4], [5, 6]]);
+
+        let c: SMatrix<i32, 2, 2> = a * b;
+
+        assert_eq!(c.as_array(), &[[22, 28], [49, 64]]);
+    }
+
+    #[test]
+    fn smatrix_matrix_trait_test() {
+        let m = SMatrix::<i32, 2, 3>::from_vec_as_rows(vec![1, 2, 3]);
+
+        assert_eq!(m.get_rows(), 2);
+        assert_eq!(m.get_columns(), 3);
+        assert_eq!(m.as_slice(), &[1, 2, 3, 1, 2, 3]);
+        assert_eq!(m.row(1), &[1, 2, 3]);
+        assert_eq!(m[1][2], 3);
+    }
+
+    #[test]
+    fn smatrix_identity_test() {
+        let m = SMatrix::<i32, 2, 3>::identity();
+
+        assert_eq!(m.to_cmatrix(), CMatrix::identity(2, 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn smatrix_set_elements_panic_test() {
+        let mut m = SMatrix::<i32, 2, 2>::zero();
+
+        m.set_elements(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    }
+
+    #[test]
+    fn smatrix_read_from_test() {
+        let m = SMatrix::<i32, 2, 3>::read_from("1;2;3\n4;5;6".as_bytes(), ';').unwrap();
+        let flat = SMatrix::<i32, 2, 3>::read_from("1;2;3;4;5;6".as_bytes(), ';').unwrap();
+
+        assert_eq!(m.as_array(), &[[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(flat, m);
+        assert!(SMatrix::<i32, 3, 2>::read_from("1;2;3\n4;5;6".as_bytes(), ';').is_err());
+        assert!(SMatrix::<i32, 2, 2>::read_from("1;a".as_bytes(), ';').is_err());
+    }
+
+    #[test]
+    fn smatrix_try_from_cmatrix_test() {
+        let c = CMatrix::from_vec_as_rows(2, vec![1, 2, 3]);
+
+        assert_eq!(SMatrix::<i32, 2, 3>::try_from_cmatrix(c.clone()).unwrap().as_array(), &[[1, 2, 3], [1, 2, 3]]);
+        assert!(matches!(
+            SMatrix::<i32, 3, 2>::try_from_cmatrix(c.clone()),
+            Err(MatrixError::DimensionMismatch { expected: (3, 2), found: (2, 3) })
+        ));
+        assert!(Matrix2::try_from_cmatrix(c).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::SMatrix;
+
+use std::ops::Add;
+use std::ops::Index;
+use std::ops::IndexMut;
+use std::ops::{Mul, Sub};
+
+use crate::Scalar;
+pub use crate::cmatrix::cmatrix::CMatrix;
+pub use crate::matrix::matrix::Matrix;
+pub use crate::matrix23_trait::matrix23::Matrix23;
+
+impl<T: Scalar, const R: usize, const C: usize> Add<SMatrix<T, R, C>> for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn add(mut self, rhs: SMatrix<T, R, C>) -> SMatrix<T, R, C> {
+        for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *a = a.clone() + b.clone();
+        }
+
+        self
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Add<CMatrix<T>> for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn add(mut self, rhs: CMatrix<T>) -> SMatrix<T, R, C> {
+        if C == rhs.get_columns() && R == rhs.get_rows() {
+            for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+                *a = a.clone() + b.clone();
+            }
+        } else {
+            panic!("Can't fold this matrices: self.columns != rhs.columns || self.elems != rhs.elems");
+        }
+
+        self
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Sub<SMatrix<T, R, C>> for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn sub(mut self, rhs: SMatrix<T, R, C>) -> SMatrix<T, R, C> {
+        for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+            *a = a.clone() - b.clone();
+        }
+
+        self
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Sub<CMatrix<T>> for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn sub(mut self, rhs: CMatrix<T>) -> SMatrix<T, R, C> {
+        if C == rhs.get_columns() && R == rhs.get_rows() {
+            for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+                *a = a.clone() - b.clone();
+            }
+        } else {
+            panic!("Can't fold this matrices: self.columns != rhs.columns || self.elems != rhs.elems");
+        }
+
+        self
+    }
+}
+
+impl<T: Scalar, const R: usize, const K: usize, const C: usize> Mul<SMatrix<T, K, C>> for SMatrix<T, R, K> {
+    type Output = SMatrix<T, R, C>;
+
+    fn mul(self, rhs: SMatrix<T, K, C>) -> SMatrix<T, R, C> {
+        let mut result = SMatrix::<T, R, C>::zero();
+
+        for (out, row) in result.elems.iter_mut().zip(self.elems.iter()) {
+            for (a, rhs_row) in row.iter().zip(rhs.elems.iter()) {
+                for (o, b) in out.iter_mut().zip(rhs_row) {
+                    *o = o.clone() + a.clone() * b.clone();
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Mul<T> for SMatrix<T, R, C> {
+    type Output = SMatrix<T, R, C>;
+
+    fn mul(mut self, rhs: T) -> SMatrix<T, R, C> {
+        for a in self.as_mut_slice().iter_mut() {
+            *a = a.clone() * rhs.clone();
+        }
+
+        self
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Mul<CMatrix<T>> for SMatrix<T, R, C> {
+    type Output = CMatrix<T>;
+
+    fn mul(self, rhs: CMatrix<T>) -> CMatrix<T> {
+        let m = self;
+        m.multiplicate(rhs)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Index<(usize, usize)> for SMatrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.elems[index.0][index.1]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> IndexMut<(usize, usize)> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        &mut self.elems[index.0][index.1]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Index<usize> for SMatrix<T, R, C> {
+    type Output = [T; C];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.elems[index]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> IndexMut<usize> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, index: usize) -> &mut [T; C] {
+        &mut self.elems[index]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> PartialEq for SMatrix<T, R, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.elems == other.elems
+    }
+}
@@ -0,0 +1,309 @@
+pub mod svd {
+    use crate::{CMatrix, CMatrixTrait, Matrix, MatrixError};
+
+    const MAX_SWEEPS: usize = 64;
+
+    /// Thin singular value decomposition made with one-sided Jacobi rotations: A = U * Σ * V^T.
+    /// For matrix m x n and k = min(m, n), U is m x k, Σ has k values sorted in descending order, V^T is k x n
+    #[derive(Debug, Clone)]
+    pub struct SVD {
+        pub(crate) u: CMatrix<f64>,
+        pub(crate) s: Vec<f64>,
+        pub(crate) vt: CMatrix<f64>,
+    }
+
+    impl SVD {
+        /// Factorizes matrix of size m x n. Returns `NoConvergence` for non-finite input or if Jacobi sweeps are exhausted
+        pub(crate) fn factorize(a: CMatrix<f64>) -> Result<SVD, MatrixError> {
+            let (m, n) = (a.get_rows(), a.get_columns());
+
+            if m < n {
+                let mut at = a;
+                at.transpose();
+                let svd = SVD::factorize(at)?;
+
+                let (mut u, mut vt) = (svd.vt, svd.u);
+                u.transpose();
+                vt.transpose();
+                return Ok(SVD { u, s: svd.s, vt });
+            }
+
+            let mut cols = columns(&a);
+            let mut v: Vec<Vec<f64>> = (0..n)
+                .map(|j| (0..n).map(|i| if i == j { 1.0 } else { 0.0 }).collect())
+                .collect();
+
+            rotate(&mut cols, Some(&mut v))?;
+
+            let mut order: Vec<(f64, usize)> = cols.iter().map(|c| norm(c)).zip(0..n).collect();
+            order.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+            let s: Vec<f64> = order.iter().map(|&(s, _)| s).collect();
+            let tol = s.first().map_or(0.0, |&s0| s0 * m as f64 * f64::EPSILON);
+
+            let mut u_cols: Vec<Vec<f64>> = Vec::with_capacity(n);
+            let mut missing = Vec::new();
+            for (i, &(s, j)) in order.iter().enumerate() {
+                if s > tol {
+                    u_cols.push(cols[j].iter().map(|e| e / s).collect());
+                } else {
+                    u_cols.push(vec![0.0; m]);
+                    missing.push(i);
+                }
+            }
+            complete_basis(&mut u_cols, &missing);
+
+            let mut u = CMatrix::zero(m, n);
+            let mut vt = CMatrix::zero(n, n);
+            for (j, col) in u_cols.iter().enumerate() {
+                for (i, &e) in col.iter().enumerate() {
+                    u[(i, j)] = e;
+                }
+            }
+            for (i, &(_, j)) in order.iter().enumerate() {
+                vt[i].copy_from_slice(&v[j]);
+            }
+
+            Ok(SVD { u, s, vt })
+        }
+
+        /// Counts singular values only, without U and V
+        pub(crate) fn values(a: CMatrix<f64>) -> Result<Vec<f64>, MatrixError> {
+            let mut a = a;
+            if a.get_rows() < a.get_columns() {
+                a.transpose();
+            }
+
+            let mut cols = columns(&a);
+            rotate(&mut cols, None)?;
+
+            let mut s: Vec<f64> = cols.iter().map(|c| norm(c)).collect();
+            s.sort_by(|a, b| b.total_cmp(a));
+            Ok(s)
+        }
+
+        /// Returns matrix of left singular vectors U
+        pub fn u(&self) -> CMatrix<f64> {
+            self.u.clone()
+        }
+
+        /// Returns singular values in descending order
+        pub fn singular_values(&self) -> &[f64] {
+            &self.s
+        }
+
+        /// Returns diagonal matrix Σ
+        pub fn sigma(&self) -> CMatrix<f64> {
+            let k = self.s.len();
+            let mut sigma = CMatrix::zero(k, k);
+
+            for (i, &s) in self.s.iter().enumerate() {
+                sigma[(i, i)] = s;
+            }
+            sigma
+        }
+
+        /// Returns matrix of right singular vectors V^T
+        pub fn vt(&self) -> CMatrix<f64> {
+            self.vt.clone()
+        }
+
+        /// Counts numerical rank: amount of singular values bigger than max(m, n) * eps * σ_max
+        pub fn rank(&self) -> usize {
+            let tol = self.tolerance();
+            self.s.iter().filter(|&&s| s > tol).count()
+        }
+
+        /// Counts condition number σ_max / σ_min. Returns infinity for rank deficient matrix
+        pub fn condition_number(&self) -> f64 {
+            match (self.s.first(), self.s.last()) {
+                (Some(_), Some(&min)) if min <= self.tolerance() => f64::INFINITY,
+                (Some(&max), Some(&min)) => max / min,
+                _ => 0.0,
+            }
+        }
+
+        /// Counts Moore-Penrose pseudo-inverse V * Σ^+ * U^T. Singular values below tolerance are treated as zero
+        pub fn pseudo_inverse(&self) -> CMatrix<f64> {
+            let (m, n) = (self.u.get_rows(), self.vt.get_columns());
+            let tol = self.tolerance();
+            let mut pinv = CMatrix::zero(n, m);
+
+            for (k, &s) in self.s.iter().enumerate() {
+                if s <= tol {
+                    continue;
+                }
+                for i in 0..n {
+                    let v = self.vt[(k, i)] / s;
+                    for j in 0..m {
+                        pinv[(i, j)] += v * self.u[(j, k)];
+                    }
+                }
+            }
+            pinv
+        }
+
+        fn tolerance(&self) -> f64 {
+            let (m, n) = (self.u.get_rows(), self.vt.get_columns());
+            self.s.first().map_or(0.0, |&s| s * m.max(n) as f64 * f64::EPSILON)
+        }
+    }
+
+    /// Orthogonalizes columns with Jacobi rotations, accumulating them into `v` if it is given.
+    /// Returns `NoConvergence` if columns have non-finite elements or aren't orthogonal after `MAX_SWEEPS` sweeps
+    fn rotate(cols: &mut [Vec<f64>], mut v: Option<&mut [Vec<f64>]>) -> Result<(), MatrixError> {
+        let n = cols.len();
+
+        if cols.iter().flatten().any(|x| !x.is_finite()) {
+            return Err(MatrixError::NoConvergence);
+        }
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let alpha = dot(&cols[p], &cols[p]);
+                    let beta = dot(&cols[q], &cols[q]);
+                    let gamma = dot(&cols[p], &cols[q]);
+
+                    if gamma == 0.0 || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+
+                    apply(cols, p, q, c, s);
+                    if let Some(v) = v.as_mut() {
+                        apply(v, p, q, c, s);
+                    }
+                }
+            }
+
+            if !rotated {
+                return Ok(());
+            }
+        }
+        Err(MatrixError::NoConvergence)
+    }
+
+    /// Rotates pair of columns p and q
+    fn apply(cols: &mut [Vec<f64>], p: usize, q: usize, c: f64, s: f64) {
+        let (left, right) = cols.split_at_mut(q);
+
+        for (a, b) in left[p].iter_mut().zip(right[0].iter_mut()) {
+            let (x, y) = (*a, *b);
+            *a = c * x - s * y;
+            *b = s * x + c * y;
+        }
+    }
+
+    /// Replaces columns with given indices by unit vectors orthogonal to all other columns
+    fn complete_basis(cols: &mut [Vec<f64>], missing: &[usize]) {
+        let m = cols.first().map_or(0, |c| c.len());
+        let mut candidate = 0;
+
+        for &i in missing {
+            while candidate < m {
+                let mut e = vec![0.0; m];
+                e[candidate] = 1.0;
+                candidate += 1;
+
+                for (j, col) in cols.iter().enumerate() {
+                    if j != i {
+                        let d = dot(&e, col);
+                        for (x, &y) in e.iter_mut().zip(col) {
+                            *x -= d * y;
+                        }
+                    }
+                }
+
+                let len = norm(&e);
+                if len > 0.5 {
+                    cols[i] = e.iter().map(|x| x / len).collect();
+                    break;
+                }
+            }
+        }
+    }
+
+    fn columns(a: &CMatrix<f64>) -> Vec<Vec<f64>> {
+        (0..a.get_columns())
+            .map(|j| (0..a.get_rows()).map(|i| a[(i, j)]).collect())
+            .collect()
+    }
+
+    fn dot(a: &[f64], b: &[f64]) -> f64 {
+        a.iter().zip(b).map(|(x, y)| x * y).sum()
+    }
+
+    fn norm(a: &[f64]) -> f64 {
+        dot(a, a).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cmat, CMatrix, CMatrixTrait, Matrix, MatrixError};
+
+    #[test]
+    fn svd_factors_test() {
+        let a: CMatrix<f64> = cmat![3.0, 2.0, 2.0; 2.0, 3.0, -2.0];
+        let svd = a.svd();
+
+        assert_eq!(svd.u().get_rows(), 2);
+        assert_eq!(svd.vt().get_columns(), 3);
+        assert!((svd.singular_values()[0] - 5.0).abs() < 1e-12);
+        assert!((svd.singular_values()[1] - 3.0).abs() < 1e-12);
+        assert!((svd.u() * svd.sigma() * svd.vt() - a).norm() < 1e-12);
+    }
+
+    #[test]
+    fn svd_values_test() {
+        let a = cmat![1.0f32, 0.0; 0.0, -2.0; 0.0, 0.0];
+
+        assert_eq!(a.singular_values(), vec![2.0, 1.0]);
+        assert_eq!(a.svd().singular_values(), &[2.0, 1.0]);
+        assert_eq!(a.svd().condition_number(), 2.0);
+    }
+
+    #[test]
+    fn svd_rank_deficient_test() {
+        let a: CMatrix<f64> = cmat![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+        let svd = a.svd();
+        let mut ut = svd.u();
+        ut.transpose();
+
+        assert_eq!(svd.rank(), 1);
+        assert_eq!(svd.condition_number(), f64::INFINITY);
+        assert!((ut * svd.u() - CMatrix::identity(2, 2)).norm() < 1e-12);
+        assert!((svd.u() * svd.sigma() * svd.vt() - a).norm() < 1e-12);
+    }
+
+    #[test]
+    fn svd_pseudo_inverse_test() {
+        let a: CMatrix<f64> = cmat![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+        let pinv = a.svd().pseudo_inverse();
+
+        assert!((pinv.clone() * a.clone() - CMatrix::identity(2, 2)).norm() < 1e-12);
+        assert!((a.clone() * pinv * a.clone() - a).norm() < 1e-12);
+    }
+
+    #[test]
+    fn svd_non_finite_test() {
+        let nan: CMatrix<f64> = cmat![1.0, f64::NAN; 2.0, 3.0];
+        let inf: CMatrix<f64> = cmat![1.0, 2.0, f64::INFINITY];
+
+        assert!(matches!(nan.try_svd(), Err(MatrixError::NoConvergence)));
+        assert!(matches!(inf.try_svd(), Err(MatrixError::NoConvergence)));
+        assert!(matches!(nan.try_singular_values(), Err(MatrixError::NoConvergence)));
+
+        // overflowing dot products turn finite input into infinities during rotations
+        let huge: CMatrix<f64> = cmat![1e300, 1e300; 1e300, -1e299];
+        assert!(matches!(huge.try_svd(), Err(MatrixError::NoConvergence)));
+    }
+}
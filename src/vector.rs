@@ -0,0 +1,292 @@
+pub mod vector {
+    use crate::{Real, Scalar};
+    use crate::matrix::matrix::flatten_rows;
+    pub use crate::cmatrix::cmatrix::CMatrix;
+    pub use crate::matrix::matrix::Matrix;
+    pub use crate::matrix23_trait::matrix23::Matrix23;
+    use crate::smatrix::smatrix::SMatrix;
+
+    /// Column vector of size known at compile time
+    pub type SVector<T, const N: usize> = SMatrix<T, N, 1>;
+    /// Column vector of 2 elements
+    pub type Vector2<T> = SVector<T, 2>;
+    /// Column vector of 3 elements
+    pub type Vector3<T> = SVector<T, 3>;
+    /// Column vector of 4 elements
+    pub type Vector4<T> = SVector<T, 4>;
+
+    impl<T: Scalar> Vector2<T> {
+        pub fn new(x: T, y: T) -> Vector2<T> {
+            SMatrix::from_array([[x], [y]])
+        }
+    }
+
+    impl<T: Scalar> Vector3<T> {
+        pub fn new(x: T, y: T, z: T) -> Vector3<T> {
+            SMatrix::from_array([[x], [y], [z]])
+        }
+
+        /// Counts cross product of vectors
+        pub fn cross(&self, rhs: &Vector3<T>) -> Vector3<T> {
+            let (a, b) = (self.as_slice(), rhs.as_slice());
+
+            Vector3::new(
+                a[1].clone() * b[2].clone() - a[2].clone() * b[1].clone(),
+                a[2].clone() * b[0].clone() - a[0].clone() * b[2].clone(),
+                a[0].clone() * b[1].clone() - a[1].clone() * b[0].clone(),
+            )
+        }
+    }
+
+    impl<T: Scalar> Vector4<T> {
+        pub fn new(x: T, y: T, z: T, w: T) -> Vector4<T> {
+            SMatrix::from_array([[x], [y], [z], [w]])
+        }
+    }
+
+    impl<T: Scalar, const N: usize> SVector<T, N> {
+        /// Returns amount of elements in vector
+        pub fn len(&self) -> usize {
+            N
+        }
+
+        /// Checks if vector has no elements
+        pub fn is_empty(&self) -> bool {
+            N == 0
+        }
+
+        /// Counts dot product of vectors
+        pub fn dot(&self, rhs: &SVector<T, N>) -> T {
+            dot(self.as_slice(), rhs.as_slice())
+        }
+
+        /// Returns vector of unit length with the same direction
+        pub fn normalize(&self) -> SVector<f64, N>
+        where
+            T: Real,
+        {
+            let norm = self.norm();
+            let mut v = SVector::<f64, N>::zero();
+
+            for (a, b) in v.as_mut_slice().iter_mut().zip(self.as_slice()) {
+                *a = b.to_f64().unwrap_or(f64::NAN) / norm;
+            }
+            v
+        }
+
+        /// Counts outer product of vectors: self * rhs^T
+        pub fn outer<const M: usize>(&self, rhs: &SVector<T, M>) -> SMatrix<T, N, M> {
+            let mut m = SMatrix::<T, N, M>::zero();
+
+            for (row, a) in m.elems.iter_mut().zip(self.as_slice()) {
+                for (e, b) in row.iter_mut().zip(rhs.as_slice()) {
+                    *e = a.clone() * b.clone();
+                }
+            }
+            m
+        }
+    }
+
+    /// Column vector of custom size
+    #[derive(Debug, Default, Clone, Eq)]
+    pub struct CVector<T: Scalar> {
+        pub(crate) elems: Vec<T>,
+    }
+
+    impl<T: Scalar> CVector<T> {
+        /// Creates a vector from its elements
+        pub fn new(v: Vec<T>) -> CVector<T> {
+            CVector { elems: v }
+        }
+
+        /// Creates a vector of custom size with zero as its elements
+        pub fn zero(len: usize) -> CVector<T> {
+            CVector::from_element(len, T::zero())
+        }
+
+        /// Creates a vector of custom size from element
+        pub fn from_element(len: usize, e: T) -> CVector<T> {
+            CVector { elems: vec![e; len] }
+        }
+
+        /// Returns amount of elements in vector
+        pub fn len(&self) -> usize {
+            self.elems.len()
+        }
+
+        /// Checks if vector has no elements
+        pub fn is_empty(&self) -> bool {
+            self.elems.is_empty()
+        }
+
+        /// Counts dot product of vectors
+        pub fn dot(&self, rhs: &CVector<T>) -> T {
+            if self.len() != rhs.len() {
+                panic!("Can't count dot product: self.len != rhs.len");
+            }
+            dot(&self.elems, &rhs.elems)
+        }
+
+        /// Counts cross product of vectors. Both vectors must have 3 elements
+        pub fn cross(&self, rhs: &CVector<T>) -> CVector<T> {
+            if self.len() != 3 || rhs.len() != 3 {
+                panic!("Can't count cross product: vectors must have 3 elements");
+            }
+
+            let (a, b) = (&self.elems, &rhs.elems);
+
+            CVector::new(vec![
+                a[1].clone() * b[2].clone() - a[2].clone() * b[1].clone(),
+                a[2].clone() * b[0].clone() - a[0].clone() * b[2].clone(),
+                a[0].clone() * b[1].clone() - a[1].clone() * b[0].clone(),
+            ])
+        }
+
+        /// Returns vector of unit length with the same direction
+        pub fn normalize(&self) -> CVector<f64>
+        where
+            T: Real,
+        {
+            let norm = self.norm();
+            CVector::new(self.elems.iter().map(|e| e.to_f64().unwrap_or(f64::NAN) / norm).collect())
+        }
+
+        /// Counts outer product of vectors: self * rhs^T
+        pub fn outer(&self, rhs: &CVector<T>) -> CMatrix<T> {
+            let mut elems = Vec::with_capacity(self.len() * rhs.len());
+
+            for a in self.elems.iter() {
+                elems.extend(rhs.elems.iter().map(|b| a.clone() * b.clone()));
+            }
+
+            CMatrix {
+                rows: self.len(),
+                columns: rhs.len(),
+                elems,
+            }
+        }
+
+        /// Converts vector to CMatrix with one column
+        pub fn to_cmatrix(self) -> CMatrix<T> {
+            CMatrix {
+                rows: self.len(),
+                columns: 1,
+                elems: self.elems,
+            }
+        }
+    }
+
+    impl<T: Scalar> Matrix<T> for CVector<T> {
+        fn check_size(&self) {}
+
+        fn get_columns(&self) -> usize {
+            1
+        }
+
+        fn get_rows(&self) -> usize {
+            self.elems.len()
+        }
+
+        fn set_elements(&mut self, v: Vec<Vec<T>>) {
+            if v.iter().all(|row| row.len() == 1) {
+                let (_, _, elems) = flatten_rows(v);
+                self.elems = elems;
+            } else {
+                panic!("Can't make CVector from this elements! Every row must have 1 element");
+            }
+        }
+
+        fn as_slice(&self) -> &[T] {
+            &self.elems
+        }
+
+        fn as_mut_slice(&mut self) -> &mut [T] {
+            &mut self.elems
+        }
+
+        fn into_elements(self) -> Vec<T> {
+            self.elems
+        }
+    }
+
+    fn dot<T: Scalar>(a: &[T], b: &[T]) -> T {
+        a.iter()
+            .zip(b)
+            .fold(T::zero(), |acc, (x, y)| acc + x.clone() * y.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cmat, CMatrix, CMatrixTrait, CVector, Matrix, Matrix2, Matrix23, Matrix3, Vector2, Vector3, Vector4};
+
+    #[test]
+    fn vector3_dot_cross_test() {
+        let a = Vector3::new(1, 2, 3);
+        let b = Vector3::new(4, 5, 6);
+
+        assert_eq!(a.dot(&b), 32);
+        assert_eq!(a.cross(&b), Vector3::new(-3, 6, -3));
+        assert_eq!(a.cross(&b).dot(&a), 0);
+    }
+
+    #[test]
+    fn vector_norm_test() {
+        let v = Vector2::new(3.0, 4.0);
+
+        assert_eq!(v.norm(), 5.0);
+        assert_eq!(v.normalize(), Vector2::new(0.6, 0.8));
+        assert_eq!(Vector4::new(1, 1, 1, 1).norm(), 2.0);
+    }
+
+    #[test]
+    fn vector_outer_test() {
+        let a = Vector2::new(1, 2);
+        let b = Vector3::new(3, 4, 5);
+
+        assert_eq!(a.outer(&b).to_cmatrix(), cmat![3, 4, 5; 6, 8, 10]);
+    }
+
+    #[test]
+    fn matrix_vector_mul_test() {
+        let m2 = Matrix2::new(1, 2, 3, 4);
+        let m3 = Matrix3::new(1, 0, 0, 0, 2, 0, 0, 0, 3);
+
+        assert_eq!(m2 * Vector2::new(1, 1), Vector2::new(3, 7));
+        assert_eq!(m3 * Vector3::new(1, 1, 1), Vector3::new(1, 2, 3));
+    }
+
+    #[test]
+    fn cmatrix_cvector_mul_test() {
+        let m = cmat![1, 2, 3; 4, 5, 6];
+        let v = CVector::new(vec![1, 0, 1]);
+
+        assert_eq!(m * v.clone(), CVector::new(vec![4, 10]));
+        assert_eq!(v.dot(&v), 2);
+        assert_eq!(v.outer(&v).get_rows(), 3);
+    }
+
+    #[test]
+    fn cvector_matrix_trait_test() {
+        let v = CVector::new(vec![1.0, 2.0, 2.0]);
+        let (r, pivots) = v.rref();
+
+        assert_eq!(v.norm(), 3.0);
+        assert_eq!(v.get_elements(), vec![vec![1.0], vec![2.0], vec![2.0]]);
+        assert_eq!(v.checked_add(&v).unwrap(), CVector::new(vec![2.0, 4.0, 4.0]));
+        assert_eq!((r, pivots), (CVector::new(vec![1.0, 0.0, 0.0]), vec![0]));
+        assert_eq!(v.try_get_rows(1, 2).unwrap(), cmat![2.0; 2.0]);
+
+        let mut t = v.to_cmatrix();
+        t.transpose();
+        assert_eq!(t, cmat![1.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cmatrix_cvector_mul_panic_test() {
+        let m: CMatrix<i32> = cmat![1, 2; 3, 4];
+
+        let _ = m * CVector::new(vec![1, 2, 3]);
+    }
+}
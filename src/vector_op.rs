@@ -0,0 +1,86 @@
+pub use crate::matrix::matrix::Matrix;
+use crate::smatrix::smatrix::SMatrix;
+use crate::{CMatrix, CVector};
+
+use crate::Scalar;
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+impl<T: Scalar> Add<CVector<T>> for CVector<T> {
+    type Output = CVector<T>;
+
+    fn add(mut self, rhs: CVector<T>) -> CVector<T> {
+        if self.len() != rhs.len() {
+            panic!("Can't fold this vectors: self.len != rhs.len");
+        }
+
+        for (a, b) in self.elems.iter_mut().zip(rhs.elems) {
+            *a = a.clone() + b;
+        }
+
+        self
+    }
+}
+
+impl<T: Scalar> Sub<CVector<T>> for CVector<T> {
+    type Output = CVector<T>;
+
+    fn sub(mut self, rhs: CVector<T>) -> CVector<T> {
+        if self.len() != rhs.len() {
+            panic!("Can't fold this vectors: self.len != rhs.len");
+        }
+
+        for (a, b) in self.elems.iter_mut().zip(rhs.elems) {
+            *a = a.clone() - b;
+        }
+
+        self
+    }
+}
+
+impl<T: Scalar> Mul<T> for CVector<T> {
+    type Output = CVector<T>;
+
+    fn mul(mut self, rhs: T) -> CVector<T> {
+        for a in self.elems.iter_mut() {
+            *a = a.clone() * rhs.clone();
+        }
+
+        self
+    }
+}
+
+impl<T: Scalar> Mul<CVector<T>> for CMatrix<T> {
+    type Output = CVector<T>;
+
+    fn mul(self, rhs: CVector<T>) -> CVector<T> {
+        CVector::new(self.multiplicate(rhs).into_elements())
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Mul<CVector<T>> for SMatrix<T, R, C> {
+    type Output = CVector<T>;
+
+    fn mul(self, rhs: CVector<T>) -> CVector<T> {
+        CVector::new(self.multiplicate(rhs).into_elements())
+    }
+}
+
+impl<T: Scalar> Index<usize> for CVector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.elems[index]
+    }
+}
+
+impl<T: Scalar> IndexMut<usize> for CVector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.elems[index]
+    }
+}
+
+impl<T: Scalar> PartialEq for CVector<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.elems == other.elems
+    }
+}